@@ -39,7 +39,11 @@ pub fn arm_wait() {
     }
 }
 
-/// Stabs the bot in front of you, killing it.
+/// Stabs the bot in front of you, taking away one of its hit points.
+///
+/// Bot whose hit points drop to zero dies - how many hit points bots are born
+/// with depends on the world (see: [`health_max()`]); by default it's one, so
+/// a single stab kills.
 ///
 /// If there's nobody there, nothing happens (but the cooldown is still
 /// applied).
@@ -73,6 +77,12 @@ pub fn arm_stab() {
 /// If there's no object in front of you or you don't have any more space in the
 /// inventory, nothing happens (but the cooldown is still applied).
 ///
+/// Some objects behave differently:
+///
+/// - batteries (`'&'`) don't go into the inventory - they are consumed on the
+///   spot and recharge your energy,
+/// - mines (`'!'`) and teleporter pads (`'o'`) can't be picked at all.
+///
 /// # Cooldown
 ///
 /// ```text
@@ -123,3 +133,147 @@ pub fn arm_pick() {
 pub fn arm_drop(idx: u8) {
     wri(MEM_ARM, 0, cmd(0x03, idx, 0x00, 0x00));
 }
+
+/// Takes object from the inventory and gives it to the bot in front of you,
+/// putting it under the zeroth index of their inventory (same as
+/// [`arm_pick()`] would).
+///
+/// Indices of objects in your inventory get shifted the same way as with
+/// [`arm_drop()`].
+///
+/// If you don't have object with given index, there's nobody in front of you
+/// or the other bot's inventory is full, nothing happens (but the cooldown is
+/// still applied).
+///
+/// # Cooldown
+///
+/// ```text
+/// 60_000 +- 15% ticks (~930 ms)
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// arm_wait();
+/// arm_pick();
+///
+/// motor_wait();
+/// motor_turn_left();
+/// motor_wait();
+/// motor_turn_left();
+///
+/// arm_wait();
+/// arm_give(0); // gives whatever got picked before to the bot behind us
+/// ```
+#[inline(always)]
+pub fn arm_give(idx: u8) {
+    wri(MEM_ARM, 0, cmd(0x04, idx, 0x00, 0x00));
+}
+
+/// Digs the wall in front of you, turning it into floor and putting a brick
+/// into your inventory under the zeroth index (same as [`arm_pick()`] would).
+///
+/// Bricks can be later used to build walls, see: [`arm_build()`].
+///
+/// If there's no wall in front of you or you don't have any more space in the
+/// inventory, nothing happens (but the cooldown is still applied).
+///
+/// Digging is available only in worlds that allow terraforming - in other
+/// worlds this function does nothing (and no cooldown is applied).
+///
+/// # Cooldown
+///
+/// ```text
+/// 120_000 +- 15% ticks (~1860 ms)
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// arm_wait();
+/// arm_dig();
+/// ```
+#[inline(always)]
+pub fn arm_dig() {
+    wri(MEM_ARM, 0, cmd(0x05, 0x00, 0x00, 0x00));
+}
+
+/// Takes brick from the inventory and builds a wall in front of you, shifting
+/// following objects into their previous indices (same as [`arm_drop()`]
+/// would).
+///
+/// If object with given index is not a brick or the tile in front of you is
+/// not an empty floor (e.g. there's a bot or an object there), nothing happens
+/// (but the cooldown is still applied).
+///
+/// Same as [`arm_dig()`], building is available only in worlds that allow
+/// terraforming.
+///
+/// # Cooldown
+///
+/// ```text
+/// 120_000 +- 15% ticks (~1860 ms)
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// arm_wait();
+/// arm_dig();
+///
+/// motor_wait();
+/// motor_turn_left();
+/// motor_turn_left();
+///
+/// arm_wait();
+/// arm_build(0); // puts the wall back, behind us
+/// ```
+#[inline(always)]
+pub fn arm_build(idx: u8) {
+    wri(MEM_ARM, 0, cmd(0x06, idx, 0x00, 0x00));
+}
+
+/// Uses the tile in front of you:
+///
+/// - switch (`'\\'`) gets flipped, opening or closing all doors on its channel,
+/// - closed door (`'+'`) gets opened, provided you carry a key (the key gets
+///   used up),
+/// - open door (`'\''`) gets closed, provided there's nothing in the doorway
+///   and the door is wired to some switch or pressure plate (doors that can be
+///   opened only with a key can't be closed).
+///
+/// Note that doors can be also opened by pressure plates (`'_'`), which keep
+/// doors on their channel open for as long as there's a bot standing on them.
+///
+/// If there's nothing usable in front of you, nothing happens (but the
+/// cooldown is still applied).
+///
+/// # Cooldown
+///
+/// ```text
+/// 60_000 +- 15% ticks (~930 ms)
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// radar_wait();
+///
+/// // If there's a switch in front of us, flip it
+/// if radar_scan_3x3().at(0, -1) == '\\' {
+///     arm_wait();
+///     arm_use();
+/// }
+/// ```
+#[inline(always)]
+pub fn arm_use() {
+    wri(MEM_ARM, 0, cmd(0x07, 0x00, 0x00, 0x00));
+}
@@ -0,0 +1,58 @@
+use crate::{wri, MEM_DISPLAY};
+
+pub const DISPLAY_COLOR_DEFAULT: u32 = 0;
+pub const DISPLAY_COLOR_RED: u32 = 1;
+pub const DISPLAY_COLOR_GREEN: u32 = 2;
+pub const DISPLAY_COLOR_BLUE: u32 = 3;
+pub const DISPLAY_COLOR_YELLOW: u32 = 4;
+pub const DISPLAY_COLOR_PINK: u32 = 5;
+pub const DISPLAY_COLOR_WHITE: u32 = 6;
+
+/// Sets the character that's drawn in place of the bot on the map.
+///
+/// This doesn't affect the bot in any way - it's just a handy way of showing
+/// what the bot is currently up to (e.g. which state of its state machine it's
+/// in), visible at a glance for everyone watching the map.
+///
+/// Only printable ASCII characters (`!` to `~`) are accepted, others are
+/// ignored; use [`display_clear()`] to go back to the default `@`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// display_set_glyph('?'); // exploring
+/// display_set_glyph('!'); // attacking
+/// ```
+#[inline(always)]
+pub fn display_set_glyph(ch: char) {
+    wri(MEM_DISPLAY, 0, ch as u32);
+}
+
+/// Sets the color used to draw the bot on the map.
+///
+/// Available colors are the `DISPLAY_COLOR_*` constants, with
+/// [`DISPLAY_COLOR_DEFAULT`] going back to bot's id-based color; other values
+/// are ignored.
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// if battery_energy() < 1000 {
+///     display_set_color(DISPLAY_COLOR_RED);
+/// }
+/// ```
+#[inline(always)]
+pub fn display_set_color(color: u32) {
+    wri(MEM_DISPLAY, 1, color);
+}
+
+/// Resets both the glyph and the color back to defaults.
+#[inline(always)]
+pub fn display_clear() {
+    display_set_glyph('\0');
+    display_set_color(DISPLAY_COLOR_DEFAULT);
+}
@@ -0,0 +1,31 @@
+use crate::{rdi, MEM_HEALTH};
+
+/// Returns the number of hit points the bot has left.
+///
+/// Each stab from another bot's arm takes away one hit point - when this
+/// number drops to zero, the bot dies.
+///
+/// See also: [`health_max()`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// if health_points() < health_max() {
+///     // we've been hit, run away!
+/// }
+/// ```
+#[inline(always)]
+pub fn health_points() -> u32 {
+    rdi(MEM_HEALTH, 0)
+}
+
+/// Returns the number of hit points the bot was born with.
+///
+/// This number is configured by the world's policy and it doesn't change
+/// throughout the bot's life.
+#[inline(always)]
+pub fn health_max() -> u32 {
+    rdi(MEM_HEALTH, 1)
+}
@@ -10,17 +10,27 @@
 mod arm;
 mod battery;
 mod compass;
+mod display;
+mod health;
+mod marker;
 mod motor;
+mod nvram;
 mod panic;
 mod radar;
+mod rng;
 mod serial;
 mod timer;
 
 pub use self::arm::*;
 pub use self::battery::*;
 pub use self::compass::*;
+pub use self::display::*;
+pub use self::health::*;
+pub use self::marker::*;
 pub use self::motor::*;
+pub use self::nvram::*;
 pub use self::radar::*;
+pub use self::rng::*;
 pub use self::serial::*;
 pub use self::timer::*;
 use core::ptr;
@@ -33,6 +43,12 @@ const MEM_MOTOR: *mut u32 = MEM.wrapping_byte_add(3 * 1024);
 const MEM_ARM: *mut u32 = MEM.wrapping_byte_add(4 * 1024);
 const MEM_RADAR: *mut u32 = MEM.wrapping_byte_add(5 * 1024);
 const MEM_COMPASS: *mut u32 = MEM.wrapping_byte_add(6 * 1024);
+const MEM_HEALTH: *mut u32 = MEM.wrapping_byte_add(7 * 1024);
+const MEM_DISPLAY: *mut u32 = MEM.wrapping_byte_add(8 * 1024);
+const MEM_NVRAM: *mut u32 = MEM.wrapping_byte_add(9 * 1024);
+const MEM_RNG: *mut u32 = MEM.wrapping_byte_add(13 * 1024);
+const MEM_MARKER: *mut u32 = MEM.wrapping_byte_add(14 * 1024);
+const MEM_RADAR_MARKERS: *mut u32 = MEM.wrapping_byte_add(15 * 1024);
 
 #[inline(always)]
 fn rdi(ptr: *mut u32, off: usize) -> u32 {
@@ -63,3 +79,5 @@ core::arch::global_asm!(
         ebreak
     "#,
 );
+
+#[cfg(target_arch = "riscv32")] #[path = "zz_shim_allocator.rs"] mod zz_shim_allocator; // SHIM
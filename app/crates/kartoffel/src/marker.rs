@@ -0,0 +1,91 @@
+use crate::{cmd, rdi, wri, MEM_MARKER};
+
+/// Returns whether the marker is ready and [`marker_drop()`] or
+/// [`marker_set()`] can be invoked.
+///
+/// See also: [`marker_wait()`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// if is_marker_ready() {
+///     marker_drop(255);
+/// }
+/// ```
+#[inline(always)]
+pub fn is_marker_ready() -> bool {
+    rdi(MEM_MARKER, 0) == 1
+}
+
+/// Waits for the marker to become ready.
+///
+/// See also: [`is_marker_ready()`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// marker_wait();
+/// marker_drop(255);
+/// ```
+#[inline(always)]
+pub fn marker_wait() {
+    while !is_marker_ready() {
+        //
+    }
+}
+
+/// Leaves a marker with given value on the tile you're standing on.
+///
+/// Markers work like pheromones - other bots (and you) can sense them through
+/// the radar (see: [`RadarScan::marker_at()`](crate::RadarScan::marker_at()))
+/// and, say, follow the trail.
+///
+/// Markers left by this function decay over time: every ~16k ticks (~250 ms)
+/// the value goes down by one, until it reaches zero and the marker
+/// disappears. If you need a marker that stays put, use [`marker_set()`].
+///
+/// Leaving a marker overwrites whatever marker was there before; value of zero
+/// removes the marker.
+///
+/// # Cooldown
+///
+/// ```text
+/// 10_000 +- 15% ticks (~156 ms)
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// loop {
+///     marker_wait();
+///     marker_drop(255);
+///
+///     motor_wait();
+///     motor_step_fw();
+/// }
+/// ```
+#[inline(always)]
+pub fn marker_drop(value: u8) {
+    wri(MEM_MARKER, 0, cmd(0x01, value, 0x01, 0x00));
+}
+
+/// Leaves a non-decaying marker with given value on the tile you're standing
+/// on.
+///
+/// See: [`marker_drop()`].
+///
+/// # Cooldown
+///
+/// ```text
+/// 10_000 +- 15% ticks (~156 ms)
+/// ```
+#[inline(always)]
+pub fn marker_set(value: u8) {
+    wri(MEM_MARKER, 0, cmd(0x01, value, 0x00, 0x00));
+}
@@ -65,6 +65,8 @@ pub fn motor_pulse(left: i8, right: i8) {
 
 /// Moves the bot one tile forward in the direction it's facing.
 ///
+/// Outcome of this move can be checked with [`motor_last_move()`].
+///
 /// See also: [`motor_step_bw()`], [`motor_pulse()`].
 ///
 /// # Cooldown
@@ -88,6 +90,8 @@ pub fn motor_step_fw() {
 
 /// Moves the bot one tile away (backward) from the direction it's facing.
 ///
+/// Outcome of this move can be checked with [`motor_last_move()`].
+///
 /// See also: [`motor_step_fw()`], [`motor_pulse()`].
 ///
 /// # Cooldown
@@ -154,3 +158,74 @@ pub fn motor_turn_left() {
 pub fn motor_turn_right() {
     motor_pulse(1, -1);
 }
+
+/// Returns the outcome of the latest [`motor_step_fw()`] or
+/// [`motor_step_bw()`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// motor_wait();
+/// motor_step_fw();
+///
+/// match motor_last_move() {
+///     MotorMove::Moved => println!("moved"),
+///     MotorMove::BlockedByWall => println!("blocked by wall"),
+///     MotorMove::BlockedByBot => println!("blocked by bot"),
+///     MotorMove::BlockedByObject => println!("blocked by object"),
+///     _ => println!("huh?"),
+/// }
+/// ```
+#[inline(always)]
+pub fn motor_last_move() -> MotorMove {
+    MotorMove::from_raw(rdi(MEM_MOTOR, 1))
+}
+
+/// Outcome of a move, see: [`motor_last_move()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum MotorMove {
+    /// No move has been made yet.
+    None = 0,
+
+    /// Bot has moved.
+    Moved = 1,
+
+    /// Bot has been blocked by a wall.
+    BlockedByWall = 2,
+
+    /// Bot has been blocked by another bot.
+    BlockedByBot = 3,
+
+    /// Bot has been blocked by an object.
+    BlockedByObject = 4,
+
+    /// Motor wasn't ready (see: [`motor_wait()`]), so nothing happened.
+    NotReady = 5,
+}
+
+impl MotorMove {
+    #[inline(always)]
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            1 => Self::Moved,
+            2 => Self::BlockedByWall,
+            3 => Self::BlockedByBot,
+            4 => Self::BlockedByObject,
+            5 => Self::NotReady,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Returns how many tiles the bot has travelled since it was born
+/// (odometer).
+///
+/// Only successful moves are counted, i.e. being blocked by a wall doesn't
+/// increase this number.
+#[inline(always)]
+pub fn motor_steps() -> u32 {
+    rdi(MEM_MOTOR, 2)
+}
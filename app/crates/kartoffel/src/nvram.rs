@@ -0,0 +1,42 @@
+use crate::{rdi, wri, MEM_NVRAM};
+
+/// Size of the non-volatile memory, in words.
+const NVRAM_SIZE: usize = 1024;
+
+/// Reads a word from the non-volatile memory.
+///
+/// Non-volatile memory (NVRAM) is a 4 KiB region that - unlike the regular
+/// RAM - survives bot's reincarnation: when the bot dies and gets respawned,
+/// everything it has written into the NVRAM is still there, allowing for bots
+/// that learn between lives.
+///
+/// NVRAM is addressed in words, so `idx` must be within `0..1024` - passing
+/// anything past that panics. Freshly uploaded bot has its NVRAM zeroed.
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// let lives = nvram_read(0);
+///
+/// println!("i've died {lives} times so far");
+///
+/// nvram_write(0, lives + 1);
+/// ```
+#[inline(always)]
+pub fn nvram_read(idx: usize) -> u32 {
+    assert!(idx < NVRAM_SIZE);
+
+    rdi(MEM_NVRAM, idx)
+}
+
+/// Writes a word into the non-volatile memory.
+///
+/// See: [`nvram_read()`].
+#[inline(always)]
+pub fn nvram_write(idx: usize, val: u32) {
+    assert!(idx < NVRAM_SIZE);
+
+    wri(MEM_NVRAM, idx, val);
+}
@@ -1,4 +1,4 @@
-use crate::{cmd, rdi, wri, MEM_RADAR};
+use crate::{cmd, rdi, wri, MEM_RADAR, MEM_RADAR_MARKERS};
 use core::num::NonZeroU64;
 
 /// Returns whether the radar is ready and [`radar_scan()`] can be invoked.
@@ -170,19 +170,32 @@ pub fn radar_scan_9x9() -> RadarScan<9> {
 ///
 /// Meaning of the returned number depends on `z`:
 ///
-/// - `z=0` returns the tile located at `dx,dy` (see: [`RadarScan::at()`]),
+/// - `z=0` returns the tile located at `dx,dy` (see: [`RadarScan::at()`]) -
+///   for bots, the second byte additionally says whether the bot is a friend
+///   (`1`) or a foe (`2`), provided the world has teams (see:
+///   [`RadarScan::is_friend_at()`]); for conveyors, it says where the conveyor
+///   pushes (see: [`RadarScan::conveyor_at()`]),
 ///
 /// - `z=1` returns the higher 32 bits of the id of the bot located at `dx,dy`
 ///   (see: [`RadarScan::bot_at()`]),
 ///
 /// - `z=2` returns the lower 32 bits of the id of the bot located at `dx,dy`
-///   (see: [`RadarScan::bot_at()`]).
+///   (see: [`RadarScan::bot_at()`]),
+///
+/// - `z=3` returns value of the marker located at `dx,dy` (see:
+///   [`RadarScan::marker_at()`]).
 pub fn radar_read(r: usize, dx: i8, dy: i8, z: u8) -> u32 {
     let x = (dx + (r as i8 / 2)) as usize;
     let y = (dy + (r as i8 / 2)) as usize;
     let z = z as usize;
 
-    rdi(MEM_RADAR, 1 + z * r * r + y * r + x)
+    // Markers live in a separate address space, since there's not enough room
+    // in the main one for the 9x9 scan
+    if z == 3 {
+        rdi(MEM_RADAR_MARKERS, y * r + x)
+    } else {
+        rdi(MEM_RADAR, 1 + z * r * r + y * r + x)
+    }
 }
 
 /// Outcome of a radar scan such as [`radar_scan_3x3()`].
@@ -202,7 +215,7 @@ pub fn radar_read(r: usize, dx: i8, dy: i8, z: u8) -> u32 {
 /// This also means that the 3x3 scan allows you to access `at(-1..=1)`, 5x5
 /// gives you `at(-2..=2)` etc.
 ///
-/// The same applies to [`Self::bot_at()`].
+/// The same applies to [`Self::bot_at()`] and [`Self::marker_at()`].
 ///
 /// # Lazyness
 ///
@@ -270,4 +283,80 @@ impl<const R: usize> RadarScan<R> {
 
         NonZeroU64::new((d1 << 32) | d2)
     }
+
+    /// Returns whether the bot at given coordinates belongs to your team:
+    ///
+    /// - `Some(true)` means it's a friend,
+    /// - `Some(false)` means it's a foe,
+    /// - `None` means there's no bot there or the world doesn't have teams.
+    ///
+    /// Killing a friend doesn't give you any points.
+    ///
+    /// # Coordinate system
+    ///
+    /// This function uses bot-centric coordinates, i.e. `is_friend_at(0, -1)`
+    /// points at the bot right in front of you - see [`RadarScan`] for
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use kartoffel::*;
+    /// #
+    /// radar_wait();
+    ///
+    /// let scan = radar_scan_3x3();
+    ///
+    /// // If there's a foe in front of us, stab them
+    /// if scan.is_friend_at(0, -1) == Some(false) && is_arm_ready() {
+    ///     arm_stab();
+    /// }
+    /// ```
+    pub fn is_friend_at(&self, dx: i8, dy: i8) -> Option<bool> {
+        match (radar_read(R, dx, dy, 0) >> 8) as u8 {
+            1 => Some(true),
+            2 => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Returns where the conveyor at given coordinates pushes bots, using the
+    /// same numbering as [`compass_dir()`](crate::compass_dir()) - i.e. `1`
+    /// is north, `2` is east, `3` is south and `4` is west; returns zero if
+    /// there's no conveyor there.
+    ///
+    /// Conveyors (`'>'`) push bots standing on them by one tile every ~16k
+    /// ticks (~0.25s); other special tiles include water (`':'`), which slows
+    /// down the motor, and lava (`'"'`), which kills bots that stay on it for
+    /// longer than ~32k ticks (~0.5s).
+    ///
+    /// Note that the direction is absolute, i.e. it doesn't depend on where
+    /// your bot is facing.
+    ///
+    /// # Coordinate system
+    ///
+    /// This function uses bot-centric coordinates, i.e. `conveyor_at(0, 0)`
+    /// points at the tile you're standing on - see [`RadarScan`] for details.
+    pub fn conveyor_at(&self, dx: i8, dy: i8) -> u32 {
+        let tile = radar_read(R, dx, dy, 0);
+
+        if tile as u8 == b'>' {
+            (tile >> 8) & 0xff
+        } else {
+            0
+        }
+    }
+
+    /// Returns value of the marker at given coordinates or zero if there's no
+    /// marker there.
+    ///
+    /// See: [`marker_drop()`](crate::marker_drop()).
+    ///
+    /// # Coordinate system
+    ///
+    /// This function uses bot-centric coordinates, i.e. `marker_at(0, 0)`
+    /// points at the tile you're standing on - see [`RadarScan`] for details.
+    pub fn marker_at(&self, dx: i8, dy: i8) -> u8 {
+        radar_read(R, dx, dy, 3) as u8
+    }
 }
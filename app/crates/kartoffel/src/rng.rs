@@ -0,0 +1,23 @@
+use crate::{rdi, MEM_RNG};
+
+/// Returns a random number.
+///
+/// Numbers come from the world's random number generator, so - as compared to
+/// [`timer_seed()`](crate::timer_seed()) - every call returns a fresh value,
+/// without the need for implementing a PRNG within the firmware.
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// if rng_next() % 2 == 0 {
+///     motor_turn_left();
+/// } else {
+///     motor_turn_right();
+/// }
+/// ```
+#[inline(always)]
+pub fn rng_next() -> u32 {
+    rdi(MEM_RNG, 0)
+}
@@ -1,4 +1,4 @@
-use crate::{rdi, MEM_TIMER};
+use crate::{rdi, wri, MEM_TIMER};
 
 /// Returns a pseudorandom number that can be used as a source of randomness
 /// for hashmaps and the like.
@@ -13,12 +13,65 @@ pub fn timer_seed() -> u32 {
 /// Returns the number of ticks that have passed since the bot's been born.
 ///
 /// This counter overflows after about 18 hours, after which it will start
-/// counting from zero.
+/// counting from zero - if your bot is meant to live longer than that, use
+/// [`timer_ticks64()`].
 #[inline(always)]
 pub fn timer_ticks() -> u32 {
     rdi(MEM_TIMER, 1)
 }
 
+/// Returns the number of ticks that have passed since the bot's been born, as
+/// a 64-bit number that (practically) doesn't overflow.
+#[inline(always)]
+pub fn timer_ticks64() -> u64 {
+    loop {
+        let hi = rdi(MEM_TIMER, 2);
+        let lo = rdi(MEM_TIMER, 1);
+
+        // If the lower word has overflowed in-between the reads, try again
+        if hi == rdi(MEM_TIMER, 2) {
+            break ((hi as u64) << 32) | (lo as u64);
+        }
+    }
+}
+
+/// Sets alarm that goes off once [`timer_ticks64()`] reaches given value.
+///
+/// Setting a new alarm replaces the previous one.
+///
+/// See also: [`is_alarm_raised()`], [`timer_clear_alarm()`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use kartoffel::*;
+/// #
+/// timer_set_alarm(timer_ticks64() + 64000);
+///
+/// while !is_alarm_raised() {
+///     // do something useful in the meantime
+/// }
+/// ```
+#[inline(always)]
+pub fn timer_set_alarm(at: u64) {
+    wri(MEM_TIMER, 4, (at >> 32) as u32);
+    wri(MEM_TIMER, 3, at as u32);
+}
+
+/// Returns whether the alarm set with [`timer_set_alarm()`] has gone off.
+///
+/// Once raised, the alarm stays raised until it's cleared (or replaced).
+#[inline(always)]
+pub fn is_alarm_raised() -> bool {
+    rdi(MEM_TIMER, 3) == 1
+}
+
+/// Clears the alarm set with [`timer_set_alarm()`].
+#[inline(always)]
+pub fn timer_clear_alarm() {
+    wri(MEM_TIMER, 5, 0);
+}
+
 /// Waits until given number of ticks has passed.
 ///
 /// # Example
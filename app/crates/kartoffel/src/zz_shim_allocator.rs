@@ -0,0 +1,10 @@
+use core::ptr::addr_of_mut;
+use talc::{ClaimOnOom, Span, Talc, Talck};
+
+static mut ARENA: [u8; 64 * 1024] = [0; 64 * 1024];
+
+#[global_allocator]
+static ALLOCATOR: Talck<spin::Mutex<()>, ClaimOnOom> = Talc::new(unsafe {
+    ClaimOnOom::new(Span::from_array(addr_of_mut!(ARENA)))
+})
+.lock();
@@ -0,0 +1,40 @@
+use std::fmt;
+
+/// Error returned by [`Cpu::tick()`](crate::Cpu::tick).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickError {
+    /// Firmware tried to access memory it shouldn't have (e.g. a null pointer
+    /// or a misaligned mmio address).
+    MemoryFault {
+        msg: &'static str,
+        addr: u32,
+        size: usize,
+    },
+
+    /// Firmware tried to execute an instruction we don't support.
+    UnknownInstruction { word: u32 },
+
+    /// Firmware executed `ebreak`.
+    Ebreak,
+
+    /// Firmware jumped onto itself - reported only in tests, so that they
+    /// don't hang forever.
+    InfiniteLoop,
+}
+
+impl fmt::Display for TickError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickError::MemoryFault { msg, addr, size } => {
+                write!(f, "{msg} on 0x{addr:08x}+{size}")
+            }
+            TickError::UnknownInstruction { word } => {
+                write!(f, "unknown instruction: 0x{word:08x}")
+            }
+            TickError::Ebreak => write!(f, "got `ebreak`"),
+            TickError::InfiniteLoop => write!(f, "infinite loop detected"),
+        }
+    }
+}
+
+impl std::error::Error for TickError {}
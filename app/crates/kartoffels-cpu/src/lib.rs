@@ -1,10 +1,12 @@
 #![allow(clippy::result_unit_err)]
 
+mod error;
 mod fw;
 mod mem;
 mod mmio;
 mod tick;
 
+pub use self::error::*;
 pub use self::fw::*;
 pub use self::mmio::*;
 use anyhow::Result;
@@ -44,14 +46,14 @@ impl Cpu {
         Self { pc, ram, regs }
     }
 
-    pub fn tick(&mut self, mmio: impl Mmio) -> Result<(), Box<str>> {
+    pub fn tick(&mut self, mmio: impl Mmio) -> Result<(), TickError> {
         self.do_tick(mmio)
     }
 
-    pub fn try_tick(&mut self, mmio: impl Mmio) -> Result<bool, Box<str>> {
+    pub fn try_tick(&mut self, mmio: impl Mmio) -> Result<bool, TickError> {
         match self.tick(mmio) {
             Ok(()) => Ok(true),
-            Err(err) if err.contains("got `ebreak`") => Ok(false),
+            Err(TickError::Ebreak) => Ok(false),
             Err(err) => Err(err),
         }
     }
@@ -1,11 +1,11 @@
-use super::{Cpu, Mmio};
+use super::{Cpu, Mmio, TickError};
 
 impl Cpu {
     pub(super) fn mem_load<M, const SIZE: usize>(
         &self,
         mmio: Option<M>,
         addr: u32,
-    ) -> Result<i32, Box<str>>
+    ) -> Result<i32, TickError>
     where
         M: Mmio,
     {
@@ -32,7 +32,7 @@ impl Cpu {
         &self,
         mmio: impl Mmio,
         addr: u32,
-    ) -> Result<i32, Box<str>> {
+    ) -> Result<i32, TickError> {
         if SIZE != 4 {
             return Err(Self::mem_fault("missized mmio load", addr, SIZE));
         }
@@ -53,7 +53,7 @@ impl Cpu {
     fn mem_load_ram<const SIZE: usize>(
         &self,
         addr: u32,
-    ) -> Result<i32, Box<str>> {
+    ) -> Result<i32, TickError> {
         let rel_addr = (addr - Self::RAM_BASE) as usize;
 
         if rel_addr + SIZE > self.ram.len() {
@@ -74,7 +74,7 @@ impl Cpu {
         mmio: Option<M>,
         addr: u32,
         val: i32,
-    ) -> Result<(), Box<str>>
+    ) -> Result<(), TickError>
     where
         M: Mmio,
     {
@@ -102,7 +102,7 @@ impl Cpu {
         mmio: impl Mmio,
         addr: u32,
         val: i32,
-    ) -> Result<(), Box<str>> {
+    ) -> Result<(), TickError> {
         if SIZE != 4 {
             return Err(Self::mem_fault("missized mmio store", addr, SIZE));
         }
@@ -122,7 +122,7 @@ impl Cpu {
         &mut self,
         addr: u32,
         val: i32,
-    ) -> Result<(), Box<str>> {
+    ) -> Result<(), TickError> {
         let rel_addr = (addr - Self::RAM_BASE) as usize;
 
         if rel_addr + SIZE > self.ram.len() {
@@ -138,7 +138,7 @@ impl Cpu {
         Ok(())
     }
 
-    fn mem_fault(msg: &str, addr: u32, size: usize) -> Box<str> {
-        format!("{msg} on 0x{addr:08x}+{size}").into()
+    fn mem_fault(msg: &'static str, addr: u32, size: usize) -> TickError {
+        TickError::MemoryFault { msg, addr, size }
     }
 }
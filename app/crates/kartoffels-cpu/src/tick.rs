@@ -1,9 +1,9 @@
-use super::{Cpu, Mmio};
+use super::{Cpu, Mmio, TickError};
 use std::cmp;
 use std::ops::{BitAnd, BitOr, BitXor};
 
 impl Cpu {
-    pub(super) fn do_tick(&mut self, mmio: impl Mmio) -> Result<(), Box<str>> {
+    pub(super) fn do_tick(&mut self, mmio: impl Mmio) -> Result<(), TickError> {
         let word = self.mem_load::<(), 4>(None, self.pc)? as u32;
 
         let op = word & 0x7f;
@@ -63,7 +63,7 @@ impl Cpu {
 
         macro_rules! unknown_instr {
             () => {
-                format!("unknown instruction: 0x{word:08x}").into()
+                TickError::UnknownInstruction { word }
             };
         }
 
@@ -558,7 +558,7 @@ impl Cpu {
                 fn jal(rd, j_imm) {
                     #[cfg(test)]
                     if j_imm == 0 {
-                        return Err("infinite loop detected".into());
+                        return Err(TickError::InfiniteLoop);
                     }
 
                     self.reg_store(rd, self.pc as i32);
@@ -582,7 +582,7 @@ impl Cpu {
 
                 match i_imm {
                     0x01 => {
-                        return Err("got `ebreak`".into());
+                        return Err(TickError::Ebreak);
                     }
 
                     _ => {
@@ -620,7 +620,7 @@ impl Cpu {
         rs1: usize,
         rs2: usize,
         op: fn(i32, i32) -> i32,
-    ) -> Result<(), Box<str>> {
+    ) -> Result<(), TickError> {
         let addr = self.regs[rs1] as u32;
 
         let old_val = self.mem_load::<(), SIZE>(None, addr)?;
@@ -83,13 +83,13 @@ async fn run_once(
 
         if let Some(event) = event {
             if let ControlFlow::Break(_) =
-                event.handle(frame, &mut state).await?
+                event.handle(frame, sess, &mut state).await?
             {
                 fade = Some(Fade::new(FadeDir::Out));
             }
         }
 
-        state.poll(frame, &mut ctrl).await?;
+        state.poll(frame, sess, &mut ctrl).await?;
 
         if let Some(fade) = &fade
             && fade.dir() == FadeDir::Out
@@ -111,6 +111,7 @@ struct State {
     modal: Option<Box<Modal>>,
     mode: Mode,
     paused: bool,
+    pending_team: Option<BotId>,
     restart: Option<oneshot::Sender<()>>,
     snapshot: Arc<WorldSnapshot>,
     snapshots: Option<SnapshotStream>,
@@ -134,6 +135,8 @@ impl State {
     }
 
     fn render(&mut self, ui: &mut Ui<Event>, sess: &Session, store: &Store) {
+        self.remember_team(sess);
+
         let [main_area, bottom_area] =
             Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
                 .areas(ui.area);
@@ -212,6 +215,7 @@ impl State {
     async fn poll(
         &mut self,
         frame: &mut Frame,
+        sess: &Session,
         ctrl: &mut GameCtrlRx,
     ) -> Result<()> {
         while let Some(event) = ctrl.recv().now_or_never().flatten() {
@@ -221,13 +225,37 @@ impl State {
         if let Some(snapshots) = &mut self.snapshots
             && let Some(snapshot) = snapshots.next().now_or_never()
         {
-            self.update_snapshot(snapshot?);
+            self.update_snapshot(sess, snapshot?);
         }
 
         Ok(())
     }
 
-    fn update_snapshot(&mut self, snapshot: Arc<WorldSnapshot>) {
+    /// Once the world assigns a team to the bot we've uploaded, remembers it
+    /// within the session, so that our next bots land in the same team.
+    fn remember_team(&mut self, sess: &Session) {
+        if let Some(id) = self.pending_team
+            && let Some(handle) = &self.handle
+            && let Some(team) = self.snapshot.bots.team_of(id)
+        {
+            sess.with(|sess| sess.set_team(handle.id(), team));
+
+            self.pending_team = None;
+        }
+    }
+
+    fn update_snapshot(
+        &mut self,
+        sess: &Session,
+        snapshot: Arc<WorldSnapshot>,
+    ) {
+        // Admins see through the fog of war
+        let snapshot = if sess.with(|sess| sess.is_admin()) {
+            snapshot
+        } else {
+            snapshot.fogged(sess.id().owner())
+        };
+
         // If map size's changed, recenter the camera - this comes handy for
         // controllers which call `world.set_map()`, e.g. the tutorial
         if snapshot.tiles.size() != self.snapshot.tiles.size() {
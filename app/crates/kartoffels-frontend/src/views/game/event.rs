@@ -5,6 +5,7 @@ use super::{
 };
 use anyhow::{anyhow, Error, Result};
 use glam::IVec2;
+use kartoffels_store::Session;
 use kartoffels_ui::Frame;
 use kartoffels_world::prelude::{BotId, Clock, CreateBotRequest};
 use std::ops::ControlFlow;
@@ -62,6 +63,7 @@ impl Event {
     pub async fn handle(
         self,
         frame: &mut Frame,
+        sess: &Session,
         state: &mut State,
     ) -> Result<ControlFlow<(), ()>> {
         match self {
@@ -165,18 +167,18 @@ impl Event {
                     let request = request.with_source(source.source());
 
                     state.modal = None;
-                    state.upload_bot(request).await?;
+                    state.upload_bot(sess, request).await?;
                 }
             },
 
             Event::UploadBot { request } => {
                 state.modal = None;
-                state.upload_bot(request).await?;
+                state.upload_bot(sess, request).await?;
             }
 
             Event::CreateBot { src, pos, follow } => {
                 state.modal = None;
-                state.create_bot(src, pos, follow).await?;
+                state.create_bot(sess, src, pos, follow).await?;
             }
 
             Event::LeaveBot => {
@@ -224,6 +226,7 @@ impl Event {
 impl State {
     async fn upload_bot(
         &mut self,
+        sess: &Session,
         request: UploadBotRequest<Vec<u8>>,
     ) -> Result<()> {
         match request.position {
@@ -238,7 +241,8 @@ impl State {
 
             BotPosition::Random => {
                 for _ in 0..request.count.get() {
-                    self.create_bot(request.source.clone(), None, true).await?;
+                    self.create_bot(sess, request.source.clone(), None, true)
+                        .await?;
                 }
             }
         }
@@ -248,15 +252,21 @@ impl State {
 
     async fn create_bot(
         &mut self,
+        sess: &Session,
         src: Vec<u8>,
         pos: Option<IVec2>,
         follow: bool,
     ) -> Result<()> {
-        let id = self
-            .handle
-            .as_ref()
-            .unwrap()
-            .create_bot(CreateBotRequest::new(src).at(pos))
+        let handle = self.handle.as_ref().unwrap();
+        let team = sess.with(|sess| sess.team(handle.id()));
+
+        let id = handle
+            .create_bot(
+                CreateBotRequest::new(src)
+                    .at(pos)
+                    .owner(sess.id().owner())
+                    .team(team),
+            )
             .await;
 
         let id = match id {
@@ -271,6 +281,12 @@ impl State {
             }
         };
 
+        // If the world's assigned our bot to a team, make sure all of our
+        // further bots join the same team (see: `State::remember_team()`)
+        if team.is_none() {
+            self.pending_team = Some(id);
+        }
+
         self.join_bot(id, follow);
 
         Ok(())
@@ -2,8 +2,11 @@ use super::{Event, Mode, State};
 use crate::BotIdExt;
 use glam::ivec2;
 use kartoffels_ui::{theme, KeyCode, Modifiers, Ui};
-use kartoffels_world::prelude::{Dir, ObjectKind, Tile, TileKind};
+use kartoffels_world::prelude::{
+    AliveBotSnapshot, BotDisplay, Dir, ObjectKind, Tile, TileKind,
+};
 use ratatui::layout::Rect;
+use ratatui::style::Color;
 use std::time::Instant;
 
 #[derive(Debug)]
@@ -82,15 +85,11 @@ impl Map {
 
         match tile.kind {
             TileKind::BOT => {
-                ch = '@';
+                let bot =
+                    state.snapshot.bots.alive.get_by_idx(tile.meta[0]).unwrap();
 
-                fg = state
-                    .snapshot
-                    .bots
-                    .alive
-                    .get_by_idx(tile.meta[0])
-                    .map(|bot| bot.id.color())
-                    .unwrap();
+                ch = bot.display.glyph().unwrap_or('@');
+                fg = bot_color(bot);
 
                 bg = theme::BG;
             }
@@ -108,24 +107,64 @@ impl Map {
                     .bots
                     .alive
                     .get_by_idx(tile.meta[0])
-                    .map(|bot| bot.id.color())
+                    .map(bot_color)
                     .unwrap();
 
                 bg = theme::BG;
             }
 
+            TileKind::CONVEYOR => {
+                ch = match tile.conveyor_dir() {
+                    Some(Dir::N) => '^',
+                    Some(Dir::E) | None => '>',
+                    Some(Dir::S) => 'v',
+                    Some(Dir::W) => '<',
+                };
+
+                fg = theme::YELLOW;
+                bg = theme::BG;
+            }
+
             TileKind::DOOR => {
                 ch = '+';
                 fg = theme::GRAY;
                 bg = theme::BG;
             }
 
+            TileKind::DOOR_OPEN => {
+                ch = '\'';
+                fg = theme::GRAY;
+                bg = theme::BG;
+            }
+
             TileKind::FLOOR => {
                 ch = '.';
                 fg = theme::DARK_GRAY;
                 bg = theme::BG;
             }
 
+            TileKind::LAVA => {
+                ch = '~';
+                fg = theme::RED;
+                bg = theme::BG;
+            }
+
+            TileKind::PLATE => {
+                ch = '_';
+                fg = theme::GRAY;
+                bg = theme::BG;
+            }
+
+            TileKind::SWITCH => {
+                ch = '\\';
+                fg = if tile.is_on() {
+                    theme::YELLOW
+                } else {
+                    theme::GRAY
+                };
+                bg = theme::BG;
+            }
+
             TileKind::WALL => {
                 ch = '#';
                 fg = theme::GRAY;
@@ -144,6 +183,24 @@ impl Map {
                 bg = theme::BG;
             }
 
+            TileKind::WATER => {
+                ch = '~';
+                fg = theme::BLUE;
+                bg = theme::BG;
+            }
+
+            ObjectKind::BATTERY => {
+                ch = '&';
+                fg = theme::GREEN;
+                bg = theme::BG;
+            }
+
+            ObjectKind::BRICK => {
+                ch = '%';
+                fg = theme::WASHED_PINK;
+                bg = theme::BG;
+            }
+
             ObjectKind::FLAG => {
                 ch = '=';
                 fg = theme::YELLOW;
@@ -156,6 +213,24 @@ impl Map {
                 bg = theme::BG;
             }
 
+            ObjectKind::KEY => {
+                ch = '/';
+                fg = theme::YELLOW;
+                bg = theme::BG;
+            }
+
+            ObjectKind::MINE => {
+                ch = '!';
+                fg = theme::RED;
+                bg = theme::BG;
+            }
+
+            ObjectKind::TELEPORTER => {
+                ch = 'o';
+                fg = theme::PINK;
+                bg = theme::BG;
+            }
+
             _ => {
                 ch = ' ';
                 fg = theme::FG;
@@ -244,6 +319,18 @@ impl Map {
     }
 }
 
+fn bot_color(bot: &AliveBotSnapshot) -> Color {
+    match bot.display.color() {
+        BotDisplay::COLOR_RED => theme::RED,
+        BotDisplay::COLOR_GREEN => theme::GREEN,
+        BotDisplay::COLOR_BLUE => theme::BLUE,
+        BotDisplay::COLOR_YELLOW => theme::YELLOW,
+        BotDisplay::COLOR_PINK => theme::PINK,
+        BotDisplay::COLOR_WHITE => theme::WHITE,
+        _ => bot.id.color(),
+    }
+}
+
 impl Default for Map {
     fn default() -> Self {
         Self {
@@ -22,6 +22,7 @@ impl BotsModal {
     const WIDTHS: &[u16] = &[
         5,                        // nth
         BotId::LENGTH as u16 + 1, // id
+        5,                        // team
         7,                        // age
         6,                        // score
         18,                       // actions
@@ -112,6 +113,7 @@ impl BotsModal {
             VRow::new(ui, Self::WIDTHS)
                 .column(Span::raw("nth"))
                 .column(Span::raw("id"))
+                .column(Span::raw("team"))
                 .column(Span::raw("age"))
                 .column(Span::raw("score"));
         });
@@ -322,6 +324,11 @@ impl UiWidget<Event> for Row<'_> {
 
         let nth = Span::raw(format!("#{}", self.nth + 1));
         let id = Span::raw(self.bot.id.to_string()).fg(self.bot.id.color());
+        let team = Span::raw(match self.bot.team {
+            Some(team) => team.to_string(),
+            None => "-".into(),
+        });
+
         let age = Span::raw(self.bot.age.time().to_string());
         let score = Span::raw(self.bot.score.to_string());
 
@@ -336,6 +343,7 @@ impl UiWidget<Event> for Row<'_> {
         VRow::new(ui, BotsModal::WIDTHS)
             .column(nth)
             .column(id)
+            .column(team)
             .column(age)
             .column(score)
             .column(inspect);
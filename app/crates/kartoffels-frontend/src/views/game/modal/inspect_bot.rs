@@ -107,6 +107,17 @@ impl InspectBotModal {
 
                 None => (),
             }
+
+            if let Some(team) = world.bots.team_of(self.id) {
+                let score = world
+                    .stats
+                    .team(team)
+                    .map(|stats| stats.score)
+                    .unwrap_or_default();
+
+                ui.space(1);
+                ui.line(format!("team = {team} (score = {score})"));
+            }
         });
 
         ui.clamp(col2, |ui| {
@@ -235,11 +246,20 @@ impl InspectBotModal {
 
             let age = life.age.unwrap_or(age);
 
+            let reason = match (life.reason, life.killer) {
+                (Some(reason), Some(killer)) => {
+                    format!("{reason} by {killer}")
+                }
+                (Some(reason), None) => reason.to_string(),
+                (None, _) => "-".into(),
+            };
+
             Row::new(vec![
                 Cell::new(born_at),
                 Cell::new(died_at),
                 Cell::new(age.time().to_string()),
                 Cell::new(life.score.to_string()),
+                Cell::new(reason),
             ])
         });
 
@@ -248,6 +268,7 @@ impl InspectBotModal {
             Constraint::Length(theme::DATETIME_LENGTH),
             Constraint::Length(7),
             Constraint::Length(5),
+            Constraint::Fill(1),
         ];
 
         let header = Row::new(vec![
@@ -255,6 +276,7 @@ impl InspectBotModal {
             Cell::new("died-at"),
             Cell::new("age"),
             Cell::new("score"),
+            Cell::new("reason"),
         ])
         .underlined();
 
@@ -8,8 +8,8 @@ use kartoffels_prefabs::DUMMY;
 use kartoffels_store::Store;
 use kartoffels_ui::{KeyCode, Msg, MsgButton, MsgLine};
 use kartoffels_world::prelude::{
-    BotId, Config, CreateBotRequest, Dir, Handle, Map, MapBuilder, Policy,
-    TileKind,
+    BotId, Config, CreateBotRequest, Dir, Handle, Map, MapBuilder, MazeTheme,
+    Policy, TileKind,
 };
 use rand::RngCore;
 use ratatui::style::Stylize;
@@ -107,8 +107,14 @@ async fn init(store: &Store, game: &GameCtrl) -> Result<(Handle, BotId)> {
     let world = store.create_private_world(Config {
         policy: Policy {
             auto_respawn: false,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: 2,
             max_queued_bots: 1,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
         },
         ..store.world_config("challenge:acyclic-maze")
     })?;
@@ -143,7 +149,9 @@ async fn create_map(mut rng: impl RngCore, mut map: MapBuilder) -> Result<Map> {
     map.begin(SIZE);
 
     utils::map::draw_borders(&mut map, AREA).await;
-    utils::map::draw_maze(&mut rng, &mut map, AREA, TIMMY_POS).await;
+    MazeTheme::new(AREA)
+        .draw(&mut rng, &mut map, TIMMY_POS)
+        .await;
     draw_entrance(&mut map).await;
 
     Ok(map.commit())
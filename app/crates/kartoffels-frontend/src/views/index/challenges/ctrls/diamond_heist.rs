@@ -130,8 +130,14 @@ async fn init(store: &Store, game: &GameCtrl) -> Result<(Handle, IVec2)> {
     let world = store.create_private_world(Config {
         policy: Policy {
             auto_respawn: false,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: 16,
             max_queued_bots: 16,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
         },
         ..store.world_config("challenge:diamond-heist")
     })?;
@@ -211,14 +217,18 @@ async fn watch(
 
     loop {
         match events.next().await?.event {
-            Event::BotDied { id, .. } => {
+            Event::BotDied { id, killer, .. } => {
                 if id == player {
                     game.msg(&PLAYER_DIED_MSG).await?;
-                } else {
-                    game.msg(&GUARD_KILLED_MSG).await?;
+
+                    return Ok(ControlFlow::Continue(()));
                 }
 
-                return Ok(ControlFlow::Continue(()));
+                if killer == Some(player) {
+                    game.msg(&GUARD_KILLED_MSG).await?;
+
+                    return Ok(ControlFlow::Continue(()));
+                }
             }
 
             Event::BotMoved { id, at } => {
@@ -7,7 +7,7 @@ use glam::{ivec2, uvec2, UVec2};
 use kartoffels_store::Store;
 use kartoffels_ui::{theme, KeyCode, Msg, MsgButton, MsgLine};
 use kartoffels_world::prelude::{
-    Config, Event, Handle, Object, ObjectId, ObjectKind, Policy,
+    Config, Event, Handle, MazeTheme, Object, ObjectId, ObjectKind, Policy,
 };
 use ratatui::style::Stylize;
 use std::ops::ControlFlow;
@@ -109,8 +109,14 @@ async fn init(
     let world = store.create_private_world(Config {
         policy: Policy {
             auto_respawn: false,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: 1,
             max_queued_bots: 1,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
         },
         ..store.world_config("challenge:personal-roomba")
     })?;
@@ -122,7 +128,8 @@ async fn init(
     utils::map::build(store, game, &world, |mut rng, mut map| async move {
         map.begin(SIZE);
 
-        utils::map::draw_maze(&mut rng, &mut map, SIZE, SIZE.as_ivec2() / 2)
+        MazeTheme::new(SIZE)
+            .draw(&mut rng, &mut map, SIZE.as_ivec2() / 2)
             .await;
 
         utils::map::draw_holes(&mut rng, &mut map, 128).await;
@@ -2,11 +2,13 @@ mod create_world;
 mod delete_world;
 mod list_worlds;
 mod rename_world;
+mod schedule_world;
 
 pub use self::create_world::*;
 pub use self::delete_world::*;
 pub use self::list_worlds::*;
 pub use self::rename_world::*;
+pub use self::schedule_world::*;
 use anyhow::{anyhow, Result};
 use clap::Parser;
 use kartoffels_store::{Session, Store};
@@ -19,6 +21,7 @@ pub enum Cmd {
     DeleteWorld(DeleteWorldCmd),
     ListWorlds(ListWorldsCmd),
     RenameWorld(RenameWorldCmd),
+    ScheduleWorld(ScheduleWorldCmd),
 
     Exit,
 }
@@ -35,10 +38,11 @@ impl Cmd {
         }
 
         match self {
-            Cmd::CreateWorld(cmd) => cmd.run(store, term)?,
+            Cmd::CreateWorld(cmd) => cmd.run(store, term).await?,
             Cmd::DeleteWorld(cmd) => cmd.run(store).await?,
             Cmd::ListWorlds(cmd) => cmd.run(store, term)?,
             Cmd::RenameWorld(cmd) => cmd.run(store).await?,
+            Cmd::ScheduleWorld(cmd) => cmd.run(store, term).await?,
 
             Cmd::Exit => {
                 return Ok(ControlFlow::Break(()));
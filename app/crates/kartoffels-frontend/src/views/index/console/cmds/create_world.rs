@@ -1,9 +1,11 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::Parser;
-use kartoffels_store::Store;
+use kartoffels_store::{Schedule, Store};
 use kartoffels_ui::Term;
-use kartoffels_world::prelude::{Config, Policy, Theme};
+use kartoffels_world::prelude::{Config, CustomTheme, Policy, Theme};
+use std::ffi::OsStr;
 use std::fmt::Write;
+use std::path::Path;
 use std::str::FromStr;
 
 #[derive(Debug, Parser)]
@@ -15,16 +17,36 @@ pub struct CreateWorldCmd {
 
     #[clap(long)]
     theme: String,
+
+    /// E.g. `monday=regenerate-map` or `monthly=new-season`; can be repeated.
+    #[clap(long)]
+    schedule: Vec<Schedule>,
 }
 
 impl CreateWorldCmd {
-    pub(super) fn run(self, store: &Store, term: &mut Term) -> Result<()> {
+    pub(super) async fn run(
+        self,
+        store: &Store,
+        term: &mut Term,
+    ) -> Result<()> {
         let policy = Policy::from_str(&self.policy).with_context(|| {
             format!("couldn't parse policy: {}", self.policy)
         })?;
 
-        let theme = Theme::from_str(&self.theme)
-            .with_context(|| format!("couldn't parse theme: {}", self.theme))?;
+        let theme = if let Some(file) = self.theme.strip_prefix("file:") {
+            // Only allow files placed directly in the store's directory
+            if Path::new(file).file_name() != Some(OsStr::new(file)) {
+                return Err(anyhow!("invalid file name: {file}"));
+            }
+
+            CustomTheme::load(&store.dir().join(file))
+                .map(Theme::Custom)
+                .with_context(|| format!("couldn't load theme: {file}"))?
+        } else {
+            Theme::from_str(&self.theme).with_context(|| {
+                format!("couldn't parse theme: {}", self.theme)
+            })?
+        };
 
         let world = store.create_public_world(Config {
             name: self.name,
@@ -33,6 +55,10 @@ impl CreateWorldCmd {
             ..Default::default()
         })?;
 
+        if !self.schedule.is_empty() {
+            store.set_world_schedules(world.id(), self.schedule).await?;
+        }
+
         writeln!(term, "id: {}", world.id())?;
 
         Ok(())
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use clap::Parser;
+use kartoffels_store::{Schedule, Store};
+use kartoffels_ui::Term;
+use kartoffels_utils::Id;
+use std::fmt::Write;
+
+#[derive(Debug, Parser)]
+pub struct ScheduleWorldCmd {
+    id: Id,
+
+    /// E.g. `monday=regenerate-map`; if none are given, prints the current
+    /// schedules.
+    schedules: Vec<Schedule>,
+
+    /// Removes all of the world's schedules.
+    #[clap(long, conflicts_with = "schedules")]
+    clear: bool,
+}
+
+impl ScheduleWorldCmd {
+    pub(super) async fn run(
+        self,
+        store: &Store,
+        term: &mut Term,
+    ) -> Result<()> {
+        if self.clear || !self.schedules.is_empty() {
+            store.set_world_schedules(self.id, self.schedules).await?;
+        } else {
+            for schedule in store.world_schedules(self.id) {
+                writeln!(term, "{schedule}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
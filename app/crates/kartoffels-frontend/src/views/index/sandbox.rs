@@ -12,7 +12,9 @@ use kartoffels_store::{Session, Store};
 use kartoffels_ui::{
     Button, FadeCtrl, FadeCtrlEvent, Frame, KeyCode, Ui, UiWidget,
 };
-use kartoffels_world::prelude::{ArenaTheme, CaveTheme, Theme};
+use kartoffels_world::prelude::{
+    ArenaTheme, CaveTheme, DungeonTheme, MazeTheme, Theme,
+};
 use tracing::debug;
 
 pub async fn run(
@@ -194,6 +196,28 @@ impl Form<'_> {
 
                 Theme::Cave(CaveTheme::new(size))
             }
+
+            SandboxTheme::Dungeon => {
+                let size = match self.size {
+                    SandboxSize::Tiny => uvec2(24, 12),
+                    SandboxSize::Small => uvec2(32, 16),
+                    SandboxSize::Medium => uvec2(64, 32),
+                    SandboxSize::Large => uvec2(128, 64),
+                };
+
+                Theme::Dungeon(DungeonTheme::new(size))
+            }
+
+            SandboxTheme::Maze => {
+                let size = match self.size {
+                    SandboxSize::Tiny => uvec2(17, 9),
+                    SandboxSize::Small => uvec2(25, 17),
+                    SandboxSize::Medium => uvec2(65, 33),
+                    SandboxSize::Large => uvec2(129, 65),
+                };
+
+                Theme::Maze(MazeTheme::new(size))
+            }
         }
     }
 }
@@ -72,8 +72,14 @@ async fn init(store: &Store, theme: Theme, game: &GameCtrl) -> Result<()> {
         name: "sandbox".into(),
         policy: Policy {
             auto_respawn: true,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: MAX_BOTS,
             max_queued_bots: MAX_BOTS,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: true,
         },
         ..Default::default()
     })?;
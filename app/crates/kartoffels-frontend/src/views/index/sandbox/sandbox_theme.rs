@@ -6,6 +6,8 @@ use std::fmt;
 pub enum SandboxTheme {
     Arena,
     Cave,
+    Dungeon,
+    Maze,
 }
 
 impl SandboxTheme {
@@ -28,13 +30,15 @@ impl SandboxTheme {
     }
 
     fn all() -> impl Iterator<Item = Self> {
-        [Self::Arena, Self::Cave].into_iter()
+        [Self::Arena, Self::Cave, Self::Dungeon, Self::Maze].into_iter()
     }
 
     fn key(&self) -> KeyCode {
         KeyCode::Char(match self {
             Self::Arena => 'a',
             Self::Cave => 'c',
+            Self::Dungeon => 'd',
+            Self::Maze => 'm',
         })
     }
 }
@@ -47,6 +51,8 @@ impl fmt::Display for SandboxTheme {
             match self {
                 Self::Arena => "arena",
                 Self::Cave => "cave",
+                Self::Dungeon => "dungeon",
+                Self::Maze => "maze",
             }
         )
     }
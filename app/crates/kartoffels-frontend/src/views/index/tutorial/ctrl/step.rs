@@ -35,8 +35,14 @@ impl TutorialCtxt {
             clock: Clock::Normal,
             policy: Policy {
                 auto_respawn: false,
+                bot_hp: 1,
+                fog: false,
                 max_alive_bots: 16,
                 max_queued_bots: 16,
+                mode: Default::default(),
+                spawn: Default::default(),
+                teams: 0,
+                terraforming: false,
             },
             theme: Some(Theme::Arena(ArenaTheme::new(12))),
             ..store.world_config("tutorial")
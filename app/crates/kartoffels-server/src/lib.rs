@@ -13,10 +13,11 @@ use std::env;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
-use tokio::{select, signal, try_join};
+use tokio::{select, signal, time, try_join};
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::fmt;
 
 const LOGO: &str = indoc! {r#"
@@ -138,6 +139,28 @@ impl Cmd {
             }
         };
 
+        let scheduler = {
+            let store = store.clone();
+            let shutdown = shutdown.clone();
+
+            async move {
+                let mut interval = time::interval(Duration::from_secs(60));
+
+                loop {
+                    select! {
+                        _ = interval.tick() => {},
+                        _ = shutdown.cancelled() => break,
+                    }
+
+                    if let Err(err) = store.run_schedules().await {
+                        warn!("couldn't run schedules: {err:?}");
+                    }
+                }
+
+                Ok(())
+            }
+        };
+
         let shutdown = async {
             wait_for_shutdown().await;
             shutdown.cancel();
@@ -148,7 +171,7 @@ impl Cmd {
 
         kartoffels_frontend::init();
 
-        try_join!(http, ssh, shutdown)?;
+        try_join!(http, ssh, scheduler, shutdown)?;
 
         Ok(())
     }
@@ -1,31 +1,39 @@
 #![feature(let_chains)]
 #![feature(try_blocks)]
 
+mod schedule;
+mod schedules;
+mod season;
 mod secret;
 mod session;
 mod sessions;
 mod world;
 mod worlds;
 
+pub use self::schedule::*;
+use self::schedules::*;
+pub use self::season::Season;
 pub use self::secret::*;
 pub use self::session::*;
 use self::sessions::*;
 pub use self::world::*;
 use self::worlds::*;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use kartoffels_utils::Id;
 use kartoffels_world::prelude::{
     Clock, Config as WorldConfig, Handle as WorldHandle,
 };
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Debug)]
 pub struct Store {
     dir: Option<PathBuf>,
     secret: Option<Secret>,
     worlds: Worlds,
+    schedules: Schedules,
     sessions: Sessions,
     testing: bool,
 }
@@ -40,6 +48,7 @@ impl Store {
         Ok(Self {
             secret,
             worlds: Worlds::new(dir).await?,
+            schedules: Schedules::new(dir).await?,
             dir: dir.map(|dir| dir.to_owned()),
             sessions: Default::default(),
             testing: false,
@@ -93,8 +102,84 @@ impl Store {
         self.worlds.rename(id, name).await
     }
 
+    /// Deletes the world together with its schedules - archived seasons are
+    /// left intact.
     pub async fn delete_world(&self, id: Id) -> Result<()> {
-        self.worlds.delete(self.dir.as_deref(), id).await
+        self.worlds.delete(self.dir.as_deref(), id).await?;
+        self.schedules.remove(self.dir.as_deref(), id).await?;
+
+        Ok(())
+    }
+
+    pub fn world_schedules(&self, id: Id) -> Vec<Schedule> {
+        self.schedules.get(id)
+    }
+
+    /// Replaces world's schedules; passing an empty list removes them.
+    pub async fn set_world_schedules(
+        &self,
+        id: Id,
+        schedules: Vec<Schedule>,
+    ) -> Result<()> {
+        if self.worlds.get(id, Some(WorldType::Public)).is_none() {
+            return Err(anyhow!("couldn't find public world `{id}`"));
+        }
+
+        self.schedules
+            .set(self.dir.as_deref(), id, schedules, Utc::now())
+            .await
+    }
+
+    /// Performs all of the scheduled jobs that are due - supposed to be called
+    /// periodically, e.g. once a minute.
+    pub async fn run_schedules(&self) -> Result<()> {
+        self.run_schedules_at(Utc::now()).await
+    }
+
+    async fn run_schedules_at(&self, now: DateTime<Utc>) -> Result<()> {
+        let dir = self.dir.as_deref();
+
+        for (id, actions) in self.schedules.due(now) {
+            let Some(world) = self.worlds.get(id, Some(WorldType::Public))
+            else {
+                continue;
+            };
+
+            info!(?id, ?actions, "running scheduled jobs");
+
+            // Archive the leaderboard before the map changes, so that bots
+            // don't get a chance to die (or score) in-between
+            let result: Result<()> = try {
+                if actions.contains(&ScheduleAction::NewSeason) {
+                    let season = Season {
+                        world: world.name().to_string(),
+                        ended_at: now,
+                        bots: world.clear_bots().await?,
+                    };
+
+                    if let Some(dir) = dir {
+                        season.archive(dir, id).await?;
+                    }
+                }
+
+                if actions.contains(&ScheduleAction::RegenerateMap) {
+                    world.regenerate_map().await?;
+                }
+            };
+
+            // Failed jobs don't get marked as done, so that they get retried
+            // during the next run
+            match result {
+                Ok(()) => {
+                    self.schedules.mark_done(dir, id, now).await?;
+                }
+                Err(err) => {
+                    warn!(?id, "scheduled job failed: {err:?}");
+                }
+            }
+        }
+
+        Ok(())
     }
 
     pub fn worlds(
@@ -0,0 +1,247 @@
+use anyhow::{anyhow, Context, Error, Result};
+use chrono::{DateTime, Datelike, Days, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Recurring job performed on a public world, e.g. "regenerate the map every
+/// Monday".
+///
+/// All schedules trigger at midnight UTC; if the server happens to be down
+/// at that time, the job gets performed as soon as the server is back up.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub when: ScheduleWhen,
+    pub actions: Vec<ScheduleAction>,
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
+impl Schedule {
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        let at = self.when.last_occurrence(now);
+
+        self.last_run_at.is_none_or(|last_run_at| last_run_at < at)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = Error;
+
+    /// Parses schedule from `when=action[+action...]`, e.g.
+    /// `monday=regenerate-map` or `monthly=new-season+regenerate-map`.
+    fn from_str(spec: &str) -> Result<Self> {
+        let (when, actions) = spec
+            .split_once('=')
+            .context("expected `when=action[+action...]`")?;
+
+        let when = when.parse()?;
+
+        let actions = actions
+            .split('+')
+            .map(|action| action.parse())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            when,
+            actions,
+            last_run_at: None,
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}=", self.when)?;
+
+        for (idx, action) in self.actions.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "+")?;
+            }
+
+            write!(f, "{action}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ScheduleWhen {
+    #[serde(rename = "daily")]
+    Daily,
+
+    #[serde(rename = "weekly")]
+    Weekly { day: Weekday },
+
+    /// Triggers on the first day of each month.
+    #[serde(rename = "monthly")]
+    Monthly,
+}
+
+impl ScheduleWhen {
+    /// Returns the most recent moment (not later than `now`) this schedule
+    /// triggers at.
+    pub fn last_occurrence(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let today = now.date_naive();
+
+        let date = match self {
+            ScheduleWhen::Daily => today,
+
+            ScheduleWhen::Weekly { day } => {
+                let days = (7 + today.weekday().num_days_from_monday()
+                    - day.num_days_from_monday())
+                    % 7;
+
+                today - Days::new(days as u64)
+            }
+
+            ScheduleWhen::Monthly => today.with_day(1).unwrap(),
+        };
+
+        date.and_time(NaiveTime::MIN).and_utc()
+    }
+}
+
+impl FromStr for ScheduleWhen {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "daily" => Ok(Self::Daily),
+            "monthly" => Ok(Self::Monthly),
+
+            s => s
+                .parse()
+                .map(|day| Self::Weekly { day })
+                .map_err(|_| anyhow!("unknown schedule: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleWhen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleWhen::Daily => write!(f, "daily"),
+            ScheduleWhen::Weekly { day } => {
+                write!(f, "{}", day.to_string().to_lowercase())
+            }
+            ScheduleWhen::Monthly => write!(f, "monthly"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleAction {
+    /// Rebuilds the map from world's theme, using a new seed.
+    #[serde(rename = "regenerate-map")]
+    RegenerateMap,
+
+    /// Archives the leaderboard into the world's `.seasons` file and removes
+    /// all bots.
+    #[serde(rename = "new-season")]
+    NewSeason,
+}
+
+impl FromStr for ScheduleAction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "regenerate-map" => Ok(Self::RegenerateMap),
+            "new-season" => Ok(Self::NewSeason),
+            _ => Err(anyhow!("unknown action: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ScheduleAction::RegenerateMap => "regenerate-map",
+                ScheduleAction::NewSeason => "new-season",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn from_str() {
+        let schedule = Schedule::from_str("mon=regenerate-map").unwrap();
+
+        assert_eq!(ScheduleWhen::Weekly { day: Weekday::Mon }, schedule.when);
+        assert_eq!(vec![ScheduleAction::RegenerateMap], schedule.actions);
+
+        let schedule =
+            Schedule::from_str("monthly=new-season+regenerate-map").unwrap();
+
+        assert_eq!(ScheduleWhen::Monthly, schedule.when);
+        assert_eq!("monthly=new-season+regenerate-map", schedule.to_string());
+
+        assert_eq!(
+            "unknown schedule: yearly",
+            Schedule::from_str("yearly=new-season")
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "unknown action: nuke",
+            Schedule::from_str("daily=nuke").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn last_occurrence() {
+        // 2025-01-15 is a Wednesday
+        let now = at(2025, 1, 15, 12);
+
+        assert_eq!(
+            at(2025, 1, 15, 0),
+            ScheduleWhen::Daily.last_occurrence(now)
+        );
+
+        assert_eq!(
+            at(2025, 1, 13, 0),
+            ScheduleWhen::Weekly { day: Weekday::Mon }.last_occurrence(now)
+        );
+
+        assert_eq!(
+            at(2025, 1, 15, 0),
+            ScheduleWhen::Weekly { day: Weekday::Wed }.last_occurrence(now)
+        );
+
+        assert_eq!(
+            at(2025, 1, 9, 0),
+            ScheduleWhen::Weekly { day: Weekday::Thu }.last_occurrence(now)
+        );
+
+        assert_eq!(
+            at(2025, 1, 1, 0),
+            ScheduleWhen::Monthly.last_occurrence(now)
+        );
+    }
+
+    #[test]
+    fn is_due() {
+        let mut schedule = Schedule::from_str("mon=regenerate-map").unwrap();
+
+        schedule.last_run_at = Some(at(2025, 1, 13, 6));
+
+        assert!(!schedule.is_due(at(2025, 1, 19, 23)));
+        assert!(schedule.is_due(at(2025, 1, 20, 0)));
+        assert!(schedule.is_due(at(2025, 1, 22, 12)));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{Schedule, ScheduleAction};
+use ahash::AHashMap;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use kartoffels_utils::Id;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tokio::fs;
+use tracing::info;
+
+#[derive(Debug, Default)]
+pub struct Schedules {
+    entries: Mutex<AHashMap<Id, Vec<Schedule>>>,
+}
+
+impl Schedules {
+    pub async fn new(dir: Option<&Path>) -> Result<Self> {
+        let entries = if let Some(dir) = dir {
+            Self::load(dir).await?
+        } else {
+            Default::default()
+        };
+
+        Ok(Self {
+            entries: Mutex::new(entries),
+        })
+    }
+
+    async fn load(dir: &Path) -> Result<AHashMap<Id, Vec<Schedule>>> {
+        let mut entries = AHashMap::new();
+        let mut files = fs::read_dir(dir).await?;
+
+        while let Some(file) = files.next_entry().await? {
+            let path = file.path();
+
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+            else {
+                continue;
+            };
+
+            let Some("schedule") =
+                path.extension().and_then(|ext| ext.to_str())
+            else {
+                continue;
+            };
+
+            info!("loading: {}", path.display());
+
+            let result: Result<()> = try {
+                let id = stem
+                    .parse()
+                    .context("couldn't extract world id from path")?;
+
+                let schedules =
+                    serde_json::from_slice(&fs::read(&path).await?)?;
+
+                entries.insert(id, schedules);
+            };
+
+            result.with_context(|| {
+                format!("couldn't load schedule: {}", path.display())
+            })?;
+        }
+
+        Ok(entries)
+    }
+
+    pub fn get(&self, id: Id) -> Vec<Schedule> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(&id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub async fn set(
+        &self,
+        dir: Option<&Path>,
+        id: Id,
+        mut schedules: Vec<Schedule>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        // Newly-added schedules start counting from now, so that e.g. adding
+        // a monthly schedule in the middle of a month doesn't trigger it
+        // straight away
+        for schedule in &mut schedules {
+            schedule.last_run_at.get_or_insert(now);
+        }
+
+        if schedules.is_empty() {
+            self.entries.lock().unwrap().remove(&id);
+        } else {
+            self.entries.lock().unwrap().insert(id, schedules.clone());
+        }
+
+        self.save(dir, id, &schedules).await
+    }
+
+    pub async fn remove(&self, dir: Option<&Path>, id: Id) -> Result<()> {
+        self.set(dir, id, Vec::new(), Utc::now()).await
+    }
+
+    /// Returns actions that should be performed on each world - once they're
+    /// done, call [`Self::mark_done()`].
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<(Id, Vec<ScheduleAction>)> {
+        let mut due = Vec::new();
+
+        for (id, schedules) in self.entries.lock().unwrap().iter() {
+            let mut actions = Vec::new();
+
+            for schedule in schedules {
+                if schedule.is_due(now) {
+                    for &action in &schedule.actions {
+                        if !actions.contains(&action) {
+                            actions.push(action);
+                        }
+                    }
+                }
+            }
+
+            if !actions.is_empty() {
+                due.push((*id, actions));
+            }
+        }
+
+        due
+    }
+
+    /// Marks schedules of given world that are due at `now` as done, so that
+    /// they don't get returned by [`Self::due()`] until their next occurrence.
+    pub async fn mark_done(
+        &self,
+        dir: Option<&Path>,
+        id: Id,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let schedules = {
+            let mut entries = self.entries.lock().unwrap();
+
+            let Some(schedules) = entries.get_mut(&id) else {
+                return Ok(());
+            };
+
+            for schedule in schedules.iter_mut() {
+                if schedule.is_due(now) {
+                    schedule.last_run_at = Some(now);
+                }
+            }
+
+            schedules.clone()
+        };
+
+        self.save(dir, id, &schedules).await
+    }
+
+    async fn save(
+        &self,
+        dir: Option<&Path>,
+        id: Id,
+        schedules: &[Schedule],
+    ) -> Result<()> {
+        let Some(dir) = dir else {
+            return Ok(());
+        };
+
+        let path = path(dir, id);
+
+        let result: Result<()> = try {
+            if schedules.is_empty() {
+                if fs::try_exists(&path).await? {
+                    fs::remove_file(&path).await?;
+                }
+            } else {
+                fs::write(&path, serde_json::to_vec_pretty(schedules)?).await?;
+            }
+        };
+
+        result.with_context(|| {
+            format!("couldn't save schedule: {}", path.display())
+        })
+    }
+}
+
+fn path(dir: &Path, id: Id) -> PathBuf {
+    dir.join(id.to_string()).with_extension("schedule")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn due() {
+        let at =
+            |d: u32, h: u32| Utc.with_ymd_and_hms(2025, 1, d, h, 0, 0).unwrap();
+        let target = Schedules::default();
+
+        let schedules = vec![
+            "mon=regenerate-map".parse().unwrap(),
+            "daily=new-season+regenerate-map".parse().unwrap(),
+        ];
+
+        // 2025-01-12 is a Sunday
+        target
+            .set(None, Id::new(1), schedules, at(12, 12))
+            .await
+            .unwrap();
+
+        assert!(target.due(at(12, 23)).is_empty());
+
+        assert_eq!(
+            vec![(
+                Id::new(1),
+                vec![ScheduleAction::RegenerateMap, ScheduleAction::NewSeason]
+            )],
+            target.due(at(13, 0)),
+        );
+
+        // Schedules that haven't been marked as done (e.g. because the job has
+        // failed) remain due
+        assert_eq!(
+            vec![(
+                Id::new(1),
+                vec![ScheduleAction::RegenerateMap, ScheduleAction::NewSeason]
+            )],
+            target.due(at(13, 1)),
+        );
+
+        target.mark_done(None, Id::new(1), at(13, 1)).await.unwrap();
+
+        assert!(target.due(at(13, 12)).is_empty());
+
+        assert_eq!(
+            vec![(
+                Id::new(1),
+                vec![ScheduleAction::NewSeason, ScheduleAction::RegenerateMap]
+            )],
+            target.due(at(14, 0)),
+        );
+
+        // ---
+
+        target.remove(None, Id::new(1)).await.unwrap();
+
+        assert!(target.due(at(20, 0)).is_empty());
+    }
+}
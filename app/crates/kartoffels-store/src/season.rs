@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use kartoffels_utils::Id;
+use kartoffels_world::prelude::BotStanding;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+/// Results of a finished season, as archived in the world's `.seasons` file
+/// (one JSON object per line, oldest first).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Season {
+    pub world: String,
+    pub ended_at: DateTime<Utc>,
+    pub bots: Vec<BotStanding>,
+}
+
+impl Season {
+    pub async fn archive(&self, dir: &Path, id: Id) -> Result<()> {
+        let path = path(dir, id);
+
+        let result: Result<()> = try {
+            let mut line = serde_json::to_vec(self)?;
+
+            line.push(b'\n');
+
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .await?
+                .write_all(&line)
+                .await?;
+        };
+
+        result.with_context(|| {
+            format!("couldn't archive season: {}", path.display())
+        })
+    }
+}
+
+pub fn path(dir: &Path, id: Id) -> PathBuf {
+    dir.join(id.to_string()).with_extension("seasons")
+}
@@ -1,6 +1,8 @@
 use derivative::Derivative;
 use kartoffels_utils::Id;
+use kartoffels_world::prelude::{Owner, Team};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::{Arc, Mutex};
 use tokio::sync::oneshot;
@@ -49,6 +51,10 @@ impl Drop for Session {
 pub struct SessionEntry {
     role: SessionRole,
     upload: Option<oneshot::Sender<Vec<u8>>>,
+
+    /// Teams this session's bots belong to, keyed by world id - this way all
+    /// bots uploaded by the same session fight on the same side.
+    teams: HashMap<Id, Team>,
 }
 
 impl SessionEntry {
@@ -60,6 +66,14 @@ impl SessionEntry {
         self.role = SessionRole::Admin;
     }
 
+    pub fn team(&self, world: Id) -> Option<Team> {
+        self.teams.get(&world).copied()
+    }
+
+    pub fn set_team(&mut self, world: Id, team: Team) {
+        self.teams.insert(world, team);
+    }
+
     pub fn request_upload(&mut self) -> SessionUploadInterest {
         let (tx, rx) = oneshot::channel();
 
@@ -105,6 +119,13 @@ impl SessionUploadInterest {
 #[derivative(Debug = "transparent")]
 pub struct SessionId(pub(crate) Id);
 
+impl SessionId {
+    /// Returns owner of bots uploaded within this session.
+    pub fn owner(&self) -> Owner {
+        Owner::new(self.0)
+    }
+}
+
 impl fmt::Display for SessionId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -311,6 +311,14 @@ impl Worlds {
             .collect()
     }
 
+    pub fn get(&self, id: Id, ty: Option<WorldType>) -> Option<WorldHandle> {
+        self.entries
+            .load()
+            .get(&id)
+            .filter(|entry| ty.is_none_or(|ty| ty == entry.ty))
+            .and_then(|entry| entry.handle.clone())
+    }
+
     pub fn public(&self) -> Arc<Vec<WorldHandle>> {
         self.public_idx.load_full()
     }
@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate test;
+
+use kartoffels_prefabs::ROBERTO;
+use kartoffels_world::prelude::*;
+use test::Bencher;
+use tokio::runtime::Runtime;
+
+#[bench]
+fn tick_single_threaded(b: &mut Bencher) {
+    bench(b, Some(1));
+}
+
+#[bench]
+fn tick_multi_threaded(b: &mut Bencher) {
+    bench(b, None);
+}
+
+fn bench(b: &mut Bencher, threads: Option<usize>) {
+    let rt = Runtime::new().unwrap();
+
+    let world = rt.block_on(async {
+        let world = kartoffels_world::create(Config {
+            clock: Clock::manual(),
+            policy: Policy {
+                bot_hp: 1,
+                max_alive_bots: 256,
+                max_queued_bots: 256,
+                ..Default::default()
+            },
+            seed: Some(Default::default()),
+            theme: Some(Theme::Arena(ArenaTheme::new(32))),
+            threads,
+            ..Default::default()
+        });
+
+        for _ in 0..256 {
+            world
+                .create_bot(CreateBotRequest::new(ROBERTO))
+                .await
+                .unwrap();
+        }
+
+        world.tick(1).await.unwrap();
+        world
+    });
+
+    b.iter(|| {
+        rt.block_on(world.tick(1_000)).unwrap();
+    });
+}
@@ -2,31 +2,48 @@ mod action;
 mod arm;
 mod battery;
 mod compass;
+mod death;
+mod display;
 mod events;
+mod health;
 mod id;
 mod inventory;
+mod marker;
 mod mmio;
 mod motor;
+mod nvram;
+mod owner;
 mod radar;
+mod rng;
 mod serial;
+mod team;
 mod timer;
 
 pub use self::action::*;
 pub use self::arm::*;
 pub use self::battery::*;
 pub use self::compass::*;
+pub use self::death::*;
+pub use self::display::*;
 pub use self::events::*;
+pub use self::health::*;
 pub use self::id::*;
 pub use self::inventory::*;
+pub use self::marker::*;
 pub use self::mmio::*;
 pub use self::motor::*;
+pub use self::nvram::*;
+pub use self::owner::*;
 pub use self::radar::*;
+pub use self::rng::*;
 pub use self::serial::*;
+pub use self::team::*;
 pub use self::timer::*;
-use crate::{AliveBots, Clock, Dir, Map, Objects, Ticks, WorldRng};
+use crate::{AliveBots, Clock, Dir, Map, Markers, Objects, Policy, Ticks};
 use glam::IVec2;
-use kartoffels_cpu::{Cpu, Firmware};
+use kartoffels_cpu::{Cpu, Firmware, TickError};
 use rand::RngCore;
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -39,15 +56,26 @@ pub struct AliveBot {
     pub compass: BotCompass,
     pub cpu: Cpu,
     pub dir: Dir,
+    pub display: BotDisplay,
     pub events: BotEvents,
     pub fw: Firmware,
+    pub health: BotHealth,
     pub id: BotId,
     pub inventory: BotInventory,
+    pub marker: BotMarker,
     pub motor: BotMotor,
+    pub nvram: BotNvram,
     pub oneshot: bool,
+
+    /// Sessions don't survive restarts, so there's no point in persisting
+    /// this.
+    #[serde(skip)]
+    pub owner: Option<Owner>,
+
     pub pos: IVec2,
     pub radar: BotRadar,
     pub serial: BotSerial,
+    pub team: Option<Team>,
     pub timer: BotTimer,
 }
 
@@ -59,10 +87,17 @@ impl AliveBot {
     const MEM_ARM: u32 = 4 * 1024;
     const MEM_RADAR: u32 = 5 * 1024;
     const MEM_COMPASS: u32 = 6 * 1024;
+    const MEM_HEALTH: u32 = 7 * 1024;
+    const MEM_DISPLAY: u32 = 8 * 1024;
+    const MEM_NVRAM: u32 = 9 * 1024;
+    const MEM_RNG: u32 = 13 * 1024;
+    const MEM_MARKER: u32 = 14 * 1024;
+    const MEM_RADAR_MARKERS: u32 = 15 * 1024;
 
     pub fn new(
         rng: &mut impl RngCore,
         clock: &Clock,
+        policy: &Policy,
         pos: IVec2,
         dir: Dir,
         mut bot: QueuedBot,
@@ -76,15 +111,21 @@ impl AliveBot {
             compass: Default::default(),
             cpu: Cpu::new(&bot.fw),
             dir,
+            display: Default::default(),
             events: bot.events,
             fw: bot.fw,
+            health: BotHealth::new(policy.bot_hp),
             id: bot.id,
             inventory: Default::default(),
+            marker: Default::default(),
             motor: Default::default(),
+            nvram: bot.nvram,
             oneshot: bot.oneshot,
+            owner: bot.owner,
             pos,
             radar: Default::default(),
             serial: Default::default(),
+            team: bot.team,
             timer: BotTimer::new(rng),
         }
     }
@@ -101,14 +142,17 @@ impl AliveBot {
         &mut self,
         bots: &AliveBots,
         map: &Map,
+        markers: &Markers,
         objects: &Objects,
-        rng: &mut WorldRng,
-    ) -> Result<Option<BotAction>, Box<str>> {
+        policy: &Policy,
+        rng: &mut ChaCha8Rng,
+    ) -> Result<Option<BotAction>, TickError> {
         let mut action = None;
 
         self.timer.tick();
         self.serial.tick();
         self.arm.tick();
+        self.marker.tick();
         self.motor.tick();
         self.radar.tick();
         self.compass.tick(self.dir);
@@ -117,7 +161,11 @@ impl AliveBot {
             arm: &mut self.arm,
             battery: &mut self.battery,
             compass: &mut self.compass,
+            display: &mut self.display,
+            health: &self.health,
+            marker: &mut self.marker,
             motor: &mut self.motor,
+            nvram: &mut self.nvram,
             radar: &mut self.radar,
             serial: &mut self.serial,
             timer: &mut self.timer,
@@ -125,11 +173,14 @@ impl AliveBot {
             ctxt: BotMmioContext {
                 action: &mut action,
                 bots,
-                dir: &mut self.dir,
+                dir: self.dir,
                 map,
+                markers,
                 objects,
+                policy,
                 pos: self.pos,
-                rng: &mut rng.0,
+                rng,
+                team: self.team,
             },
         })?;
 
@@ -150,8 +201,15 @@ pub struct QueuedBot {
     pub events: BotEvents,
     pub fw: Firmware,
     pub id: BotId,
+    pub nvram: BotNvram,
     pub oneshot: bool,
+
+    /// See: [`AliveBot::owner`].
+    #[serde(skip)]
+    pub owner: Option<Owner>,
+
     pub pos: Option<IVec2>,
     pub requeued: bool, // TODO rename to `reincarnated`
     pub serial: BotSerial,
+    pub team: Option<Team>,
 }
@@ -1,3 +1,4 @@
+use crate::Dir;
 use glam::IVec2;
 
 /// Action to apply on the world after [`AliveBot::tick()`] finishes.
@@ -6,10 +7,49 @@ use glam::IVec2;
 /// unique access to the entire `world.bots`, which conflicts with `.tick()`
 /// that needs unique access to the bot itself.
 // TODO ^ this is refactorable
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum BotAction {
-    ArmDrop { at: IVec2, idx: u8 },
-    ArmPick { at: IVec2 },
-    ArmStab { at: IVec2 },
-    MotorMove { at: IVec2 },
+    ArmBuild {
+        at: IVec2,
+        idx: u8,
+    },
+    ArmDig {
+        at: IVec2,
+    },
+    ArmDrop {
+        at: IVec2,
+        idx: u8,
+    },
+    ArmGive {
+        at: IVec2,
+        idx: u8,
+    },
+    ArmPick {
+        at: IVec2,
+    },
+    ArmStab {
+        at: IVec2,
+    },
+    ArmUse {
+        at: IVec2,
+    },
+    MarkerSet {
+        at: IVec2,
+        value: u8,
+        decays: bool,
+    },
+    MotorMove {
+        at: IVec2,
+    },
+
+    /// Turns are applied together with all the other actions, at the end of
+    /// the tick - the firmware sees the new direction starting from its next
+    /// instruction, but a bot that gets killed during the same tick doesn't
+    /// get to turn.
+    MotorTurn {
+        dir: Dir,
+    },
+    RadarScan {
+        range: u8,
+    },
 }
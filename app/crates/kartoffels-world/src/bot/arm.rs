@@ -9,6 +9,9 @@ pub struct BotArm {
 }
 
 impl BotArm {
+    /// How many hit points a single stab takes away.
+    pub const STAB_DAMAGE: u32 = 1;
+
     pub fn tick(&mut self) {
         self.cooldown = self.cooldown.saturating_sub(1);
     }
@@ -31,7 +34,7 @@ impl BotArm {
             (AliveBot::MEM_ARM, [0x01, 0x00, 0x00, 0x00]) => {
                 if self.cooldown == 0 {
                     *ctxt.action = Some(BotAction::ArmStab {
-                        at: ctxt.pos + *ctxt.dir,
+                        at: ctxt.pos + ctxt.dir,
                     });
 
                     self.cooldown = ctxt.cooldown(60_000, 15);
@@ -43,7 +46,7 @@ impl BotArm {
             (AliveBot::MEM_ARM, [0x02, 0x00, 0x00, 0x00]) => {
                 if self.cooldown == 0 {
                     *ctxt.action = Some(BotAction::ArmPick {
-                        at: ctxt.pos + *ctxt.dir,
+                        at: ctxt.pos + ctxt.dir,
                     });
 
                     self.cooldown = ctxt.cooldown(60_000, 15);
@@ -55,10 +58,60 @@ impl BotArm {
             (AliveBot::MEM_ARM, [0x03, idx, 0x00, 0x00]) => {
                 if self.cooldown == 0 {
                     *ctxt.action = Some(BotAction::ArmDrop {
-                        at: ctxt.pos + *ctxt.dir,
+                        at: ctxt.pos + ctxt.dir,
+                        idx,
+                    });
+
+                    self.cooldown = ctxt.cooldown(60_000, 15);
+                }
+
+                Ok(())
+            }
+
+            (AliveBot::MEM_ARM, [0x04, idx, 0x00, 0x00]) => {
+                if self.cooldown == 0 {
+                    *ctxt.action = Some(BotAction::ArmGive {
+                        at: ctxt.pos + ctxt.dir,
+                        idx,
+                    });
+
+                    self.cooldown = ctxt.cooldown(60_000, 15);
+                }
+
+                Ok(())
+            }
+
+            (AliveBot::MEM_ARM, [0x05, 0x00, 0x00, 0x00]) => {
+                if self.cooldown == 0 && ctxt.policy.terraforming {
+                    *ctxt.action = Some(BotAction::ArmDig {
+                        at: ctxt.pos + ctxt.dir,
+                    });
+
+                    self.cooldown = ctxt.cooldown(120_000, 15);
+                }
+
+                Ok(())
+            }
+
+            (AliveBot::MEM_ARM, [0x06, idx, 0x00, 0x00]) => {
+                if self.cooldown == 0 && ctxt.policy.terraforming {
+                    *ctxt.action = Some(BotAction::ArmBuild {
+                        at: ctxt.pos + ctxt.dir,
                         idx,
                     });
 
+                    self.cooldown = ctxt.cooldown(120_000, 15);
+                }
+
+                Ok(())
+            }
+
+            (AliveBot::MEM_ARM, [0x07, 0x00, 0x00, 0x00]) => {
+                if self.cooldown == 0 {
+                    *ctxt.action = Some(BotAction::ArmUse {
+                        at: ctxt.pos + ctxt.dir,
+                    });
+
                     self.cooldown = ctxt.cooldown(60_000, 15);
                 }
 
@@ -69,3 +122,70 @@ impl BotArm {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AliveBots, Dir, Map, Markers, Objects, Policy};
+    use glam::{ivec2, uvec2};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn terraforming() {
+        let bots = AliveBots::default();
+        let map = Map::new(uvec2(3, 3));
+        let markers = Markers::default();
+        let objects = Objects::default();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut store = |terraforming: bool, cmd: u32| {
+            let mut arm = BotArm::default();
+            let mut action = None;
+
+            let policy = Policy {
+                terraforming,
+                ..Default::default()
+            };
+
+            arm.mmio_store(
+                &mut BotMmioContext {
+                    action: &mut action,
+                    bots: &bots,
+                    dir: Dir::E,
+                    map: &map,
+                    markers: &markers,
+                    objects: &objects,
+                    policy: &policy,
+                    pos: ivec2(1, 1),
+                    rng: &mut rng,
+                    team: None,
+                },
+                AliveBot::MEM_ARM,
+                cmd,
+            )
+            .unwrap();
+
+            (action, arm.cooldown)
+        };
+
+        assert_eq!((None, 0), store(false, 0x05));
+        assert_eq!((None, 0), store(false, 0x06));
+
+        let (action, cooldown) = store(true, 0x05);
+
+        assert_eq!(Some(BotAction::ArmDig { at: ivec2(2, 1) }), action);
+        assert!(cooldown > 0);
+
+        let (action, cooldown) = store(true, 0x06);
+
+        assert_eq!(
+            Some(BotAction::ArmBuild {
+                at: ivec2(2, 1),
+                idx: 0
+            }),
+            action
+        );
+        assert!(cooldown > 0);
+    }
+}
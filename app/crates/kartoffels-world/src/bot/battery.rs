@@ -8,6 +8,14 @@ pub struct BotBattery {
 }
 
 impl BotBattery {
+    /// How much energy picking up a battery object gives.
+    pub const RECHARGE: u32 = 1024;
+
+    pub fn recharge(&mut self, energy: u32) -> u32 {
+        self.energy = self.energy.saturating_add(energy);
+        self.energy
+    }
+
     pub fn mmio_load(&self, addr: u32) -> Result<u32, ()> {
         match addr {
             AliveBot::MEM_BATTERY => Ok(self.energy),
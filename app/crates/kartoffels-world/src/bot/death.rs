@@ -0,0 +1,106 @@
+use kartoffels_cpu::TickError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum DeathReason {
+    #[serde(rename = "fell-into-void")]
+    FellIntoVoid,
+
+    #[serde(rename = "stabbed")]
+    Stabbed,
+
+    #[serde(rename = "mine")]
+    Mine,
+
+    #[serde(rename = "firmware-crashed")]
+    FirmwareCrashed { fault: FirmwareFault },
+
+    #[serde(rename = "killed-by-admin")]
+    KilledByAdmin,
+
+    #[serde(rename = "lava")]
+    Lava,
+}
+
+impl fmt::Display for DeathReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeathReason::FellIntoVoid => write!(f, "fell into the void"),
+            DeathReason::Stabbed => write!(f, "stabbed"),
+            DeathReason::Mine => write!(f, "blown up by a mine"),
+            DeathReason::FirmwareCrashed { fault } => {
+                write!(f, "firmware crashed ({fault})")
+            }
+            DeathReason::KilledByAdmin => write!(f, "killed by admin"),
+            DeathReason::Lava => write!(f, "burned in lava"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FirmwareFault {
+    #[serde(rename = "memory-fault")]
+    MemoryFault,
+
+    #[serde(rename = "unknown-instruction")]
+    UnknownInstruction,
+
+    #[serde(rename = "ebreak")]
+    Ebreak,
+
+    #[serde(rename = "other")]
+    Other,
+}
+
+impl FirmwareFault {
+    /// Classifies error returned by `Cpu::tick()`.
+    pub fn classify(err: &TickError) -> Self {
+        match err {
+            TickError::MemoryFault { .. } => FirmwareFault::MemoryFault,
+            TickError::UnknownInstruction { .. } => {
+                FirmwareFault::UnknownInstruction
+            }
+            TickError::Ebreak => FirmwareFault::Ebreak,
+            TickError::InfiniteLoop => FirmwareFault::Other,
+        }
+    }
+}
+
+impl fmt::Display for FirmwareFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FirmwareFault::MemoryFault => write!(f, "memory fault"),
+            FirmwareFault::UnknownInstruction => {
+                write!(f, "unknown instruction")
+            }
+            FirmwareFault::Ebreak => write!(f, "ebreak"),
+            FirmwareFault::Other => write!(f, "other"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(
+        TickError::MemoryFault {
+            msg: "null-pointer load",
+            addr: 0,
+            size: 4,
+        },
+        FirmwareFault::MemoryFault
+    )]
+    #[test_case(
+        TickError::UnknownInstruction { word: 0xdeadbeef },
+        FirmwareFault::UnknownInstruction
+    )]
+    #[test_case(TickError::Ebreak, FirmwareFault::Ebreak)]
+    #[test_case(TickError::InfiniteLoop, FirmwareFault::Other)]
+    fn classify(err: TickError, expected: FirmwareFault) {
+        assert_eq!(expected, FirmwareFault::classify(&err));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::AliveBot;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct BotDisplay {
+    glyph: Option<char>,
+    color: u8,
+}
+
+impl BotDisplay {
+    pub const COLOR_DEFAULT: u8 = 0;
+    pub const COLOR_RED: u8 = 1;
+    pub const COLOR_GREEN: u8 = 2;
+    pub const COLOR_BLUE: u8 = 3;
+    pub const COLOR_YELLOW: u8 = 4;
+    pub const COLOR_PINK: u8 = 5;
+    pub const COLOR_WHITE: u8 = 6;
+
+    /// Returns the character that should be drawn in place of the bot, if any.
+    pub fn glyph(&self) -> Option<char> {
+        self.glyph
+    }
+
+    /// Returns the color that should be used to draw the bot, one of
+    /// `Self::COLOR_*`.
+    pub fn color(&self) -> u8 {
+        self.color
+    }
+
+    pub fn mmio_load(&self, _addr: u32) -> Result<u32, ()> {
+        Err(())
+    }
+
+    pub fn mmio_store(&mut self, addr: u32, val: u32) -> Result<(), ()> {
+        match addr {
+            AliveBot::MEM_DISPLAY => {
+                if val == 0 {
+                    self.glyph = None;
+                } else if let Some(ch) = char::from_u32(val)
+                    && ch.is_ascii_graphic()
+                {
+                    self.glyph = Some(ch);
+                }
+
+                Ok(())
+            }
+
+            const { AliveBot::MEM_DISPLAY + 4 } => {
+                if val <= Self::COLOR_WHITE as u32 {
+                    self.color = val as u8;
+                }
+
+                Ok(())
+            }
+
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke() {
+        let mut target = BotDisplay::default();
+
+        assert_eq!(None, target.glyph());
+        assert_eq!(0, target.color());
+
+        // ---
+
+        target
+            .mmio_store(AliveBot::MEM_DISPLAY, 'x' as u32)
+            .unwrap();
+        target.mmio_store(AliveBot::MEM_DISPLAY + 4, 2).unwrap();
+
+        assert_eq!(Some('x'), target.glyph());
+        assert_eq!(2, target.color());
+
+        // ---
+
+        target
+            .mmio_store(AliveBot::MEM_DISPLAY, '\n' as u32)
+            .unwrap();
+        target
+            .mmio_store(AliveBot::MEM_DISPLAY, '\u{1F954}' as u32)
+            .unwrap();
+        target.mmio_store(AliveBot::MEM_DISPLAY + 4, 123).unwrap();
+
+        assert_eq!(Some('x'), target.glyph());
+        assert_eq!(2, target.color());
+
+        // ---
+
+        target.mmio_store(AliveBot::MEM_DISPLAY, 0).unwrap();
+        target.mmio_store(AliveBot::MEM_DISPLAY + 4, 0).unwrap();
+
+        assert_eq!(None, target.glyph());
+        assert_eq!(0, target.color());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{AliveBot, BotId, Team};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BotHealth {
+    hp: u32,
+    max_hp: u32,
+    last_hit_by: Option<Attacker>,
+}
+
+impl BotHealth {
+    pub fn new(hp: u32) -> Self {
+        let hp = hp.max(1);
+
+        Self {
+            hp,
+            max_hp: hp,
+            last_hit_by: None,
+        }
+    }
+
+    /// Takes away given number of hit points, returning how many are left.
+    pub fn damage(&mut self, amount: u32, by: Attacker) -> u32 {
+        self.hp = self.hp.saturating_sub(amount);
+        self.last_hit_by = Some(by);
+        self.hp
+    }
+
+    /// Returns the bot that's damaged us most recently.
+    pub fn last_hit_by(&self) -> Option<Attacker> {
+        self.last_hit_by
+    }
+
+    pub fn mmio_load(&self, addr: u32) -> Result<u32, ()> {
+        match addr {
+            AliveBot::MEM_HEALTH => Ok(self.hp),
+            const { AliveBot::MEM_HEALTH + 4 } => Ok(self.max_hp),
+
+            _ => Err(()),
+        }
+    }
+}
+
+/// Bot that has dealt damage, together with the team it was fighting for at
+/// that moment - attacker might be long gone by the time its victim dies, so
+/// the team can't be looked up later.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attacker {
+    pub id: BotId,
+    pub team: Option<Team>,
+}
+
+impl Attacker {
+    pub fn of(bot: &AliveBot) -> Self {
+        Self {
+            id: bot.id,
+            team: bot.team,
+        }
+    }
+}
+
+impl Default for BotHealth {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke() {
+        let mut target = BotHealth::new(3);
+
+        assert_eq!(None, target.last_hit_by());
+
+        assert_eq!(Ok(3), target.mmio_load(AliveBot::MEM_HEALTH));
+        assert_eq!(Ok(3), target.mmio_load(AliveBot::MEM_HEALTH + 4));
+
+        let by = Attacker {
+            id: BotId::new(123),
+            team: None,
+        };
+
+        assert_eq!(2, target.damage(1, by));
+        assert_eq!(Some(by), target.last_hit_by());
+        assert_eq!(Ok(2), target.mmio_load(AliveBot::MEM_HEALTH));
+        assert_eq!(Ok(3), target.mmio_load(AliveBot::MEM_HEALTH + 4));
+
+        let by = Attacker {
+            id: BotId::new(456),
+            team: Some(Team::new(1)),
+        };
+
+        assert_eq!(0, target.damage(5, by));
+        assert_eq!(Some(by), target.last_hit_by());
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_HEALTH));
+    }
+
+    #[test]
+    fn new() {
+        let target = BotHealth::new(0);
+
+        assert_eq!(Ok(1), target.mmio_load(AliveBot::MEM_HEALTH));
+    }
+}
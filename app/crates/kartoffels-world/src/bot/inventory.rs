@@ -20,11 +20,34 @@ impl BotInventory {
         }
     }
 
+    pub fn get(&self, idx: u8) -> Option<Object> {
+        self.objects.get(idx as usize).map(|obj| obj.obj)
+    }
+
+    pub fn contains(&self, id: ObjectId) -> bool {
+        self.objects.iter().any(|obj| obj.id == id)
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = ObjectId> + '_ {
+        self.objects.iter().map(|obj| obj.id)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.objects.len() >= Self::SIZE
+    }
+
     pub fn take(&mut self, idx: u8) -> Option<(ObjectId, Object)> {
         self.objects
             .remove(idx as usize)
             .map(|obj| (obj.id, obj.obj))
     }
+
+    /// Takes the most recently picked object of given kind.
+    pub fn take_kind(&mut self, kind: u8) -> Option<(ObjectId, Object)> {
+        let idx = self.objects.iter().position(|obj| obj.obj.kind == kind)?;
+
+        self.take(idx as u8)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -49,13 +72,20 @@ mod tests {
                 .unwrap();
         }
 
+        assert!(target.is_full());
+
         target
             .add(ObjectId::new(255), Object::new(255))
             .unwrap_err();
 
+        assert_eq!(32, target.get(0).unwrap().kind);
+        assert_eq!(1, target.get(31).unwrap().kind);
+        assert!(target.get(32).is_none());
+
         assert_eq!(32, target.take(0).unwrap().1.kind);
         assert_eq!(31, target.take(0).unwrap().1.kind);
         assert_eq!(30, target.take(0).unwrap().1.kind);
+        assert!(!target.is_full());
 
         target.add(ObjectId::new(255), Object::new(255)).unwrap();
 
@@ -0,0 +1,117 @@
+use super::BotAction;
+use crate::{AliveBot, BotMmioContext};
+use serde::{Deserialize, Serialize};
+
+/// Device for leaving markers on the tile the bot's standing on.
+///
+/// Markers themselves are kept within the world, see: [`crate::Markers`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BotMarker {
+    cooldown: u32,
+}
+
+impl BotMarker {
+    pub fn tick(&mut self) {
+        self.cooldown = self.cooldown.saturating_sub(1);
+    }
+
+    pub fn mmio_load(&self, addr: u32) -> Result<u32, ()> {
+        match addr {
+            AliveBot::MEM_MARKER => Ok((self.cooldown == 0) as u32),
+
+            _ => Err(()),
+        }
+    }
+
+    pub fn mmio_store(
+        &mut self,
+        ctxt: &mut BotMmioContext,
+        addr: u32,
+        val: u32,
+    ) -> Result<(), ()> {
+        match (addr, val.to_le_bytes()) {
+            (
+                AliveBot::MEM_MARKER,
+                [0x01, value, decays @ (0x00 | 0x01), 0x00],
+            ) => {
+                if self.cooldown == 0 {
+                    *ctxt.action = Some(BotAction::MarkerSet {
+                        at: ctxt.pos,
+                        value,
+                        decays: decays == 0x01,
+                    });
+
+                    self.cooldown = ctxt.cooldown(10_000, 15);
+                }
+
+                Ok(())
+            }
+
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AliveBots, Dir, Map, Markers, Objects, Policy};
+    use glam::{ivec2, uvec2};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn cooldown() {
+        let bots = AliveBots::default();
+        let map = Map::new(uvec2(3, 3));
+        let markers = Markers::default();
+        let objects = Objects::default();
+        let policy = Policy::default();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut marker = BotMarker::default();
+
+        let mut store = |marker: &mut BotMarker| {
+            let mut action = None;
+
+            marker
+                .mmio_store(
+                    &mut BotMmioContext {
+                        action: &mut action,
+                        bots: &bots,
+                        dir: Dir::E,
+                        map: &map,
+                        markers: &markers,
+                        objects: &objects,
+                        policy: &policy,
+                        pos: ivec2(1, 1),
+                        rng: &mut rng,
+                        team: None,
+                    },
+                    AliveBot::MEM_MARKER,
+                    u32::from_le_bytes([0x01, 42, 0x01, 0x00]),
+                )
+                .unwrap();
+
+            action
+        };
+
+        assert_eq!(
+            Some(BotAction::MarkerSet {
+                at: ivec2(1, 1),
+                value: 42,
+                decays: true,
+            }),
+            store(&mut marker),
+        );
+
+        assert_eq!(Ok(0), marker.mmio_load(AliveBot::MEM_MARKER));
+        assert_eq!(None, store(&mut marker));
+
+        while marker.cooldown > 0 {
+            marker.tick();
+        }
+
+        assert_eq!(Ok(1), marker.mmio_load(AliveBot::MEM_MARKER));
+        assert!(store(&mut marker).is_some());
+    }
+}
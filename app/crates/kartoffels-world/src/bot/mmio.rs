@@ -1,8 +1,8 @@
 use super::{
-    BotAction, BotArm, BotBattery, BotCompass, BotMotor, BotRadar, BotSerial,
-    BotTimer,
+    BotAction, BotArm, BotBattery, BotCompass, BotDisplay, BotHealth,
+    BotMarker, BotMotor, BotNvram, BotRadar, BotRng, BotSerial, BotTimer, Team,
 };
-use crate::{AliveBots, Dir, Map, Objects};
+use crate::{AliveBots, Dir, Map, Markers, Objects, Policy};
 use glam::IVec2;
 use kartoffels_cpu::Mmio;
 use rand::Rng;
@@ -12,7 +12,11 @@ pub struct BotMmio<'a> {
     pub arm: &'a mut BotArm,
     pub battery: &'a mut BotBattery,
     pub compass: &'a mut BotCompass,
+    pub display: &'a mut BotDisplay,
+    pub health: &'a BotHealth,
+    pub marker: &'a mut BotMarker,
     pub motor: &'a mut BotMotor,
+    pub nvram: &'a mut BotNvram,
     pub radar: &'a mut BotRadar,
     pub serial: &'a mut BotSerial,
     pub timer: &'a mut BotTimer,
@@ -20,7 +24,7 @@ pub struct BotMmio<'a> {
 }
 
 impl Mmio for BotMmio<'_> {
-    fn load(self, addr: u32) -> Result<u32, ()> {
+    fn load(mut self, addr: u32) -> Result<u32, ()> {
         self.timer
             .mmio_load(addr)
             .or_else(|_| self.battery.mmio_load(addr))
@@ -29,6 +33,11 @@ impl Mmio for BotMmio<'_> {
             .or_else(|_| self.arm.mmio_load(addr))
             .or_else(|_| self.radar.mmio_load(addr))
             .or_else(|_| self.compass.mmio_load(addr))
+            .or_else(|_| self.health.mmio_load(addr))
+            .or_else(|_| self.display.mmio_load(addr))
+            .or_else(|_| self.nvram.mmio_load(addr))
+            .or_else(|_| self.marker.mmio_load(addr))
+            .or_else(|_| BotRng::mmio_load(&mut self.ctxt, addr))
     }
 
     fn store(mut self, addr: u32, val: u32) -> Result<(), ()> {
@@ -39,17 +48,23 @@ impl Mmio for BotMmio<'_> {
             .or_else(|_| self.motor.mmio_store(&mut self.ctxt, addr, val))
             .or_else(|_| self.arm.mmio_store(&mut self.ctxt, addr, val))
             .or_else(|_| self.radar.mmio_store(&mut self.ctxt, addr, val))
+            .or_else(|_| self.display.mmio_store(addr, val))
+            .or_else(|_| self.nvram.mmio_store(addr, val))
+            .or_else(|_| self.marker.mmio_store(&mut self.ctxt, addr, val))
     }
 }
 
 pub struct BotMmioContext<'a> {
     pub action: &'a mut Option<BotAction>,
     pub bots: &'a AliveBots,
-    pub dir: &'a mut Dir,
+    pub dir: Dir,
     pub map: &'a Map,
+    pub markers: &'a Markers,
     pub objects: &'a Objects,
+    pub policy: &'a Policy,
     pub pos: IVec2,
     pub rng: &'a mut ChaCha8Rng,
+    pub team: Option<Team>,
 }
 
 impl BotMmioContext<'_> {
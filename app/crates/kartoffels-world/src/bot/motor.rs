@@ -1,20 +1,62 @@
 use super::BotAction;
-use crate::{AliveBot, BotMmioContext};
+use crate::{AliveBot, BotMmioContext, TileKind};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct BotMotor {
     cooldown: u32,
+    last_move: u32,
+    steps: u32,
 }
 
 impl BotMotor {
+    pub const MOVE_NONE: u32 = 0;
+    pub const MOVE_OK: u32 = 1;
+    pub const MOVE_BLOCKED_BY_WALL: u32 = 2;
+    pub const MOVE_BLOCKED_BY_BOT: u32 = 3;
+    pub const MOVE_BLOCKED_BY_OBJECT: u32 = 4;
+    pub const MOVE_NOT_READY: u32 = 5;
+
     pub fn tick(&mut self) {
         self.cooldown = self.cooldown.saturating_sub(1);
     }
 
+    /// Records outcome of the latest [`BotAction::MotorMove`], so that the
+    /// firmware can tell whether it has actually moved.
+    pub fn report(&mut self, outcome: u32) {
+        self.last_move = outcome;
+
+        if outcome == Self::MOVE_OK {
+            self.steps = self.steps.wrapping_add(1);
+        }
+    }
+
+    /// Records a move that didn't happen because something was in the way.
+    pub fn report_blocked(&mut self, by: MoveBlocker) {
+        self.report(match by {
+            MoveBlocker::Wall => Self::MOVE_BLOCKED_BY_WALL,
+            MoveBlocker::Bot => Self::MOVE_BLOCKED_BY_BOT,
+            MoveBlocker::Object => Self::MOVE_BLOCKED_BY_OBJECT,
+        });
+    }
+
+    /// Returns cooldown for a move, taking into account the tile bot is
+    /// currently standing on - driving out of water takes twice as long.
+    fn slow_down(ctxt: &mut BotMmioContext, base: u32) -> u32 {
+        let cooldown = ctxt.cooldown(base, 15);
+
+        if ctxt.map.get(ctxt.pos).kind == TileKind::WATER {
+            cooldown * 2
+        } else {
+            cooldown
+        }
+    }
+
     pub fn mmio_load(&self, addr: u32) -> Result<u32, ()> {
         match addr {
             AliveBot::MEM_MOTOR => Ok((self.cooldown == 0) as u32),
+            const { AliveBot::MEM_MOTOR + 4 } => Ok(self.last_move),
+            const { AliveBot::MEM_MOTOR + 8 } => Ok(self.steps),
 
             _ => Err(()),
         }
@@ -30,10 +72,13 @@ impl BotMotor {
             (AliveBot::MEM_MOTOR, [0x01, 0x01, 0x01, 0x00]) => {
                 if self.cooldown == 0 {
                     *ctxt.action = Some(BotAction::MotorMove {
-                        at: ctxt.pos + *ctxt.dir,
+                        at: ctxt.pos + ctxt.dir,
                     });
 
-                    self.cooldown = ctxt.cooldown(20_000, 15);
+                    self.cooldown = Self::slow_down(ctxt, 20_000);
+                    self.last_move = Self::MOVE_NONE;
+                } else {
+                    self.last_move = Self::MOVE_NOT_READY;
                 }
 
                 Ok(())
@@ -45,7 +90,10 @@ impl BotMotor {
                         at: ctxt.pos + ctxt.dir.turned_back(),
                     });
 
-                    self.cooldown = ctxt.cooldown(30_000, 15);
+                    self.cooldown = Self::slow_down(ctxt, 30_000);
+                    self.last_move = Self::MOVE_NONE;
+                } else {
+                    self.last_move = Self::MOVE_NOT_READY;
                 }
 
                 Ok(())
@@ -53,7 +101,9 @@ impl BotMotor {
 
             (AliveBot::MEM_MOTOR, [0x01, 0x01, 0xff, 0x00]) => {
                 if self.cooldown == 0 {
-                    *ctxt.dir = ctxt.dir.turned_right();
+                    *ctxt.action = Some(BotAction::MotorTurn {
+                        dir: ctxt.dir.turned_right(),
+                    });
 
                     self.cooldown = ctxt.cooldown(25_000, 15);
                 }
@@ -63,7 +113,9 @@ impl BotMotor {
 
             (AliveBot::MEM_MOTOR, [0x01, 0xff, 0x01, 0x00]) => {
                 if self.cooldown == 0 {
-                    *ctxt.dir = ctxt.dir.turned_left();
+                    *ctxt.action = Some(BotAction::MotorTurn {
+                        dir: ctxt.dir.turned_left(),
+                    });
 
                     self.cooldown = ctxt.cooldown(25_000, 15);
                 }
@@ -75,3 +127,13 @@ impl BotMotor {
         }
     }
 }
+
+/// What stopped a bot from moving, see: [`Event::BotMoveBlocked`].
+///
+/// [`Event::BotMoveBlocked`]: crate::Event::BotMoveBlocked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveBlocker {
+    Wall,
+    Bot,
+    Object,
+}
@@ -0,0 +1,80 @@
+use crate::AliveBot;
+use serde::{Deserialize, Serialize};
+
+/// Non-volatile memory - unlike RAM, it survives bot's reincarnation.
+///
+/// Memory is allocated lazily, upon the first non-zero write, so that bots
+/// which don't use NVRAM don't waste space in save files.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BotNvram {
+    #[serde(with = "serde_bytes")]
+    mem: Vec<u8>,
+}
+
+impl BotNvram {
+    pub const SIZE: u32 = 4 * 1024;
+
+    pub fn mmio_load(&self, addr: u32) -> Result<u32, ()> {
+        let idx = Self::idx(addr)?;
+
+        Ok(self
+            .mem
+            .get(idx..idx + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+            .unwrap_or(0))
+    }
+
+    pub fn mmio_store(&mut self, addr: u32, val: u32) -> Result<(), ()> {
+        let idx = Self::idx(addr)?;
+
+        if self.mem.is_empty() {
+            if val == 0 {
+                return Ok(());
+            }
+
+            self.mem = vec![0; Self::SIZE as usize];
+        }
+
+        self.mem[idx..idx + 4].copy_from_slice(&val.to_le_bytes());
+
+        Ok(())
+    }
+
+    fn idx(addr: u32) -> Result<usize, ()> {
+        if (AliveBot::MEM_NVRAM..AliveBot::MEM_NVRAM + Self::SIZE)
+            .contains(&addr)
+        {
+            Ok((addr - AliveBot::MEM_NVRAM) as usize)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke() {
+        let mut target = BotNvram::default();
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_NVRAM));
+        assert_eq!(Ok(()), target.mmio_store(AliveBot::MEM_NVRAM + 8, 0));
+        assert!(target.mem.is_empty());
+
+        // ---
+
+        target.mmio_store(AliveBot::MEM_NVRAM, 123).unwrap();
+        target.mmio_store(AliveBot::MEM_NVRAM + 4092, 456).unwrap();
+
+        assert_eq!(Ok(123), target.mmio_load(AliveBot::MEM_NVRAM));
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_NVRAM + 4));
+        assert_eq!(Ok(456), target.mmio_load(AliveBot::MEM_NVRAM + 4092));
+
+        // ---
+
+        assert_eq!(Err(()), target.mmio_load(AliveBot::MEM_NVRAM + 4096));
+        assert_eq!(Err(()), target.mmio_store(AliveBot::MEM_NVRAM + 4096, 1));
+    }
+}
@@ -0,0 +1,19 @@
+use kartoffels_utils::Id;
+use serde::{Deserialize, Serialize};
+
+/// Player the bot belongs to, e.g. the session that's uploaded it - in worlds
+/// with fog of war, players see only what their own bots have explored (see:
+/// [`crate::Policy::fog`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Owner(Id);
+
+impl Owner {
+    pub const fn new(id: Id) -> Self {
+        Self(id)
+    }
+
+    pub fn get(&self) -> Id {
+        self.0
+    }
+}
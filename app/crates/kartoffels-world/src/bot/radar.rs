@@ -1,14 +1,27 @@
-use crate::{AliveBot, BotMmioContext, TileKind};
+use crate::{AliveBot, BotAction, BotMmioContext, Dir, TileKind};
 use glam::{ivec2, IVec2};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BotRadar {
     scan: Vec<u32>,
+    markers: Vec<u32>,
     cooldown: u32,
 }
 
 impl BotRadar {
+    /// Second byte of the scanned bot's tile, telling whether the bot belongs
+    /// to the scanning bot's team; stays zero if any of them has no team.
+    const FRIEND: u32 = 1;
+    const FOE: u32 = 2;
+
+    /// Second byte of the scanned conveyor's tile, telling where it pushes -
+    /// numbered the same way as the compass, i.e. `1` is north, `2` is east
+    /// etc.
+    fn conveyor_dir(dir: Dir) -> u32 {
+        u8::from(dir) as u32 + 1
+    }
+
     pub fn tick(&mut self) {
         self.cooldown = self.cooldown.saturating_sub(1);
     }
@@ -17,12 +30,25 @@ impl BotRadar {
         match addr {
             AliveBot::MEM_RADAR => Ok((self.cooldown == 0) as u32),
 
-            addr if addr >= AliveBot::MEM_RADAR + 4 => {
+            addr if (AliveBot::MEM_RADAR + 4..AliveBot::MEM_RADAR + 1024)
+                .contains(&addr) =>
+            {
                 let idx = (addr - AliveBot::MEM_RADAR - 4) / 4;
 
                 self.scan.get(idx as usize).copied().ok_or(())
             }
 
+            // Scanned markers don't fit within the radar's main address space,
+            // so they get a separate one
+            addr if (AliveBot::MEM_RADAR_MARKERS
+                ..AliveBot::MEM_RADAR_MARKERS + 1024)
+                .contains(&addr) =>
+            {
+                let idx = (addr - AliveBot::MEM_RADAR_MARKERS) / 4;
+
+                self.markers.get(idx as usize).copied().ok_or(())
+            }
+
             _ => Err(()),
         }
     }
@@ -39,6 +65,10 @@ impl BotRadar {
             {
                 if self.cooldown == 0 {
                     self.do_scan(ctxt, range);
+
+                    *ctxt.action = Some(BotAction::RadarScan {
+                        range: range.len() as u8,
+                    });
                 }
 
                 Ok(())
@@ -63,9 +93,16 @@ impl BotRadar {
                 let out_z2;
 
                 if let Some(bot_id) = ctxt.bots.lookup_at(pos) {
+                    let relation = match (ctxt.team, ctxt.bots.team_of(bot_id))
+                    {
+                        (Some(a), Some(b)) if a == b => Self::FRIEND,
+                        (Some(_), Some(_)) => Self::FOE,
+                        _ => 0,
+                    };
+
                     let bot_id = bot_id.get().get();
 
-                    out_z0 = TileKind::BOT as u32;
+                    out_z0 = TileKind::BOT as u32 | (relation << 8);
                     out_z1 = (bot_id >> 32) as u32;
                     out_z2 = bot_id as u32;
                 } else if let Some(object) = ctxt.objects.get_at(pos) {
@@ -73,7 +110,14 @@ impl BotRadar {
                     out_z1 = 0;
                     out_z2 = 0;
                 } else {
-                    out_z0 = ctxt.map.get(pos).kind as u32;
+                    let tile = ctxt.map.get(pos);
+
+                    out_z0 = if let Some(dir) = tile.conveyor_dir() {
+                        tile.kind as u32 | (Self::conveyor_dir(dir) << 8)
+                    } else {
+                        tile.kind as u32
+                    };
+
                     out_z1 = 0;
                     out_z2 = 0;
                 }
@@ -81,6 +125,7 @@ impl BotRadar {
                 self.scan[range.idx(x, y, 0)] = out_z0;
                 self.scan[range.idx(x, y, 1)] = out_z1;
                 self.scan[range.idx(x, y, 2)] = out_z2;
+                self.markers[range.idx(x, y, 0)] = ctxt.markers.get(pos) as u32;
             }
         }
 
@@ -92,6 +137,7 @@ impl Default for BotRadar {
     fn default() -> Self {
         Self {
             scan: vec![0; 3 * 9 * 9],
+            markers: vec![0; 9 * 9],
             cooldown: 0,
         }
     }
@@ -141,7 +187,8 @@ impl BotRadarRange {
 mod tests {
     use super::*;
     use crate::{
-        AliveBots, BotId, Dir, Map, Object, ObjectId, ObjectKind, Objects,
+        AliveBots, BotId, Dir, Map, Markers, Object, ObjectId, ObjectKind,
+        Objects, Policy, Team,
     };
     use glam::uvec2;
     use indoc::indoc;
@@ -170,6 +217,21 @@ mod tests {
                 .collect()
         }
 
+        fn scanned_markers(&self, range: BotRadarRange) -> Vec<(u8, IVec2)> {
+            (0..range.len())
+                .flat_map(|y| {
+                    (0..range.len()).filter_map(move |x| {
+                        let addr = AliveBot::MEM_RADAR_MARKERS
+                            + 4 * range.idx(x, y, 0) as u32;
+
+                        let value = self.mmio_load(addr).unwrap() as u8;
+
+                        (value > 0).then(|| (value, ivec2(x as i32, y as i32)))
+                    })
+                })
+                .collect()
+        }
+
         fn scanned_tiles(&self, range: BotRadarRange) -> String {
             (0..range.len())
                 .map(|y| {
@@ -197,6 +259,7 @@ mod tests {
         range: u8,
         expected_bots: &'static [(BotId, IVec2)],
         expected_tiles: &'static str,
+        expected_markers: &'static [(u8, IVec2)],
         expected_cooldown: u32,
     }
 
@@ -210,6 +273,7 @@ mod tests {
             . . .
             . . .
         "},
+        expected_markers: &[(42, ivec2(1, 2))],
         expected_cooldown: 9374,
     };
 
@@ -223,6 +287,7 @@ mod tests {
             . @ .
             . . .
         "},
+        expected_markers: &[],
         expected_cooldown: 9374,
     };
 
@@ -236,6 +301,7 @@ mod tests {
             . = .
             . @ .
         "},
+        expected_markers: &[],
         expected_cooldown: 9374,
     };
 
@@ -249,6 +315,7 @@ mod tests {
             . . .
             . = .
         "},
+        expected_markers: &[],
         expected_cooldown: 9374,
     };
 
@@ -264,6 +331,7 @@ mod tests {
             . . . . .
             . . . . .
         "},
+        expected_markers: &[(42, ivec2(2, 3))],
         expected_cooldown: 15592,
     };
 
@@ -279,6 +347,7 @@ mod tests {
             . . . . .
             . . . . .
         "},
+        expected_markers: &[(42, ivec2(3, 2))],
         expected_cooldown: 15592,
     };
 
@@ -294,6 +363,7 @@ mod tests {
             . . . . .
             . . . . .
         "},
+        expected_markers: &[(42, ivec2(1, 2))],
         expected_cooldown: 15592,
     };
 
@@ -309,6 +379,7 @@ mod tests {
             . . @ . .
             . . = . .
         "},
+        expected_markers: &[(42, ivec2(2, 1))],
         expected_cooldown: 15592,
     };
 
@@ -320,7 +391,7 @@ mod tests {
     #[test_case(TEST_5X5_E)]
     #[test_case(TEST_5X5_W)]
     #[test_case(TEST_5X5_S)]
-    fn test(mut case: TestCase) {
+    fn test(case: TestCase) {
         let map = {
             let mut map = Map::new(uvec2(7, 7));
 
@@ -352,17 +423,27 @@ mod tests {
             bots
         };
 
+        let markers = {
+            let mut markers = Markers::default();
+
+            markers.set(ivec2(3, 4), 42, true);
+            markers
+        };
+
         let mut radar = BotRadar::default();
         let mut rng = ChaCha8Rng::from_seed(Default::default());
 
         let mut ctxt = BotMmioContext {
             action: &mut None,
             bots: &bots,
-            dir: &mut case.dir,
+            dir: case.dir,
             map: &map,
+            markers: &markers,
             objects: &objects,
+            policy: &Policy::default(),
             pos: case.pos,
             rng: &mut rng,
+            team: None,
         };
 
         radar
@@ -382,6 +463,96 @@ mod tests {
             radar.scanned_tiles(range).trim()
         );
 
+        assert_eq!(case.expected_markers, radar.scanned_markers(range));
         assert_eq!(case.expected_cooldown, radar.cooldown);
     }
+
+    #[test]
+    fn teams() {
+        let map = {
+            let mut map = Map::new(uvec2(3, 3));
+
+            map.rect(ivec2(0, 0), ivec2(2, 2), TileKind::FLOOR);
+            map
+        };
+
+        let bots = {
+            let mut bots = AliveBots::default();
+
+            bots.add(AliveBot {
+                id: BotId::new(1),
+                pos: ivec2(0, 0),
+                team: Some(Team::new(1)),
+                ..Default::default()
+            });
+
+            bots.add(AliveBot {
+                id: BotId::new(2),
+                pos: ivec2(2, 0),
+                team: Some(Team::new(2)),
+                ..Default::default()
+            });
+
+            bots.add(AliveBot {
+                id: BotId::new(3),
+                pos: ivec2(0, 2),
+                ..Default::default()
+            });
+
+            bots
+        };
+
+        let markers = Markers::default();
+        let objects = Objects::default();
+        let mut radar = BotRadar::default();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ctxt = BotMmioContext {
+            action: &mut None,
+            bots: &bots,
+            dir: Dir::N,
+            map: &map,
+            markers: &markers,
+            objects: &objects,
+            policy: &Policy::default(),
+            pos: ivec2(1, 1),
+            rng: &mut rng,
+            team: Some(Team::new(1)),
+        };
+
+        radar
+            .mmio_store(
+                &mut ctxt,
+                AliveBot::MEM_RADAR,
+                u32::from_le_bytes([0x01, 3, 0x00, 0x00]),
+            )
+            .unwrap();
+
+        let range = BotRadarRange::D3;
+        let tile = |x, y| radar.mmio_load(range.addr(x, y, 0)).unwrap();
+
+        assert_eq!(u32::from_le_bytes([b'@', 1, 0, 0]), tile(0, 0));
+        assert_eq!(u32::from_le_bytes([b'@', 2, 0, 0]), tile(2, 0));
+        assert_eq!(u32::from_le_bytes([b'@', 0, 0, 0]), tile(0, 2));
+        assert_eq!(u32::from(b'.'), tile(1, 0));
+    }
+
+    #[test]
+    fn address_space() {
+        let target = BotRadar::default();
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_RADAR + 4 * 243));
+        assert_eq!(Err(()), target.mmio_load(AliveBot::MEM_COMPASS));
+        assert_eq!(Err(()), target.mmio_load(AliveBot::MEM_MARKER));
+
+        assert_eq!(
+            Ok(0),
+            target.mmio_load(AliveBot::MEM_RADAR_MARKERS + 4 * 80)
+        );
+
+        assert_eq!(
+            Err(()),
+            target.mmio_load(AliveBot::MEM_RADAR_MARKERS + 4 * 81)
+        );
+    }
 }
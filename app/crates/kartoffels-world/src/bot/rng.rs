@@ -0,0 +1,66 @@
+use crate::{AliveBot, BotMmioContext};
+use rand::RngCore;
+
+/// Hardware random number generator.
+///
+/// This peripheral is stateless - numbers are drawn straight from the world's
+/// rng, so worlds created with a fixed seed stay deterministic.
+#[derive(Clone, Copy, Debug)]
+pub struct BotRng;
+
+impl BotRng {
+    pub fn mmio_load(ctxt: &mut BotMmioContext, addr: u32) -> Result<u32, ()> {
+        match addr {
+            AliveBot::MEM_RNG => Ok(ctxt.rng.next_u32()),
+
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AliveBots, Dir, Map, Markers, Objects, Policy};
+    use glam::{ivec2, uvec2};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn smoke() {
+        let bots = AliveBots::default();
+        let map = Map::new(uvec2(1, 1));
+        let markers = Markers::default();
+        let objects = Objects::default();
+        let policy = Policy::default();
+
+        let load = |rng: &mut ChaCha8Rng| {
+            BotRng::mmio_load(
+                &mut BotMmioContext {
+                    action: &mut None,
+                    bots: &bots,
+                    dir: Dir::N,
+                    map: &map,
+                    markers: &markers,
+                    objects: &objects,
+                    policy: &policy,
+                    pos: ivec2(0, 0),
+                    rng,
+                    team: None,
+                },
+                AliveBot::MEM_RNG,
+            )
+            .unwrap()
+        };
+
+        let mut rng1 = ChaCha8Rng::from_seed(Default::default());
+        let mut rng2 = ChaCha8Rng::from_seed(Default::default());
+
+        let a = load(&mut rng1);
+        let b = load(&mut rng1);
+
+        assert_ne!(a, b);
+        assert_eq!(a, load(&mut rng2));
+        assert_eq!(b, load(&mut rng2));
+    }
+}
@@ -13,6 +13,11 @@ pub struct BotSerial {
     #[serde(skip_serializing)]
     #[serde(skip_deserializing)]
     snapshot: Option<Arc<VecDeque<u32>>>,
+
+    /// Whether the output has changed since the last [`Self::take_written()`].
+    #[serde(skip_serializing)]
+    #[serde(skip_deserializing)]
+    written: bool,
 }
 
 impl BotSerial {
@@ -28,6 +33,10 @@ impl BotSerial {
             .clone()
     }
 
+    pub fn take_written(&mut self) -> bool {
+        mem::take(&mut self.written)
+    }
+
     pub fn mmio_load(&self, _addr: u32) -> Result<u32, ()> {
         Err(())
     }
@@ -45,6 +54,7 @@ impl BotSerial {
                     0xffffff01 => {
                         if self.buffering {
                             self.snapshot = None;
+                            self.written = true;
                             self.buffering = false;
                             self.curr.clear();
 
@@ -75,6 +85,7 @@ impl BotSerial {
 
                         if !self.buffering {
                             self.snapshot = None;
+                            self.written = true;
                         }
                     }
                 }
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Team the bot fights for - bots from the same team see each other as
+/// friends on the radar and don't get points for killing each other.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(transparent)]
+pub struct Team(u8);
+
+impl Team {
+    pub const fn new(id: u8) -> Self {
+        Self(id)
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
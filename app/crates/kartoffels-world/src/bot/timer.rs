@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 pub struct BotTimer {
     seed: u32,
     ticks: u64,
+    alarm: Option<u64>,
+    alarm_hi: u32,
 }
 
 impl BotTimer {
@@ -14,6 +16,8 @@ impl BotTimer {
         Self {
             seed: rng.gen(),
             ticks: 0,
+            alarm: None,
+            alarm_hi: 0,
         }
     }
 
@@ -29,12 +33,93 @@ impl BotTimer {
         match addr {
             AliveBot::MEM_TIMER => Ok(self.seed),
             const { AliveBot::MEM_TIMER + 4 } => Ok(self.ticks as u32),
+            const { AliveBot::MEM_TIMER + 8 } => Ok((self.ticks >> 32) as u32),
+            const { AliveBot::MEM_TIMER + 12 } => {
+                Ok(self.alarm.is_some_and(|alarm| self.ticks >= alarm) as u32)
+            }
 
             _ => Err(()),
         }
     }
 
-    pub fn mmio_store(&mut self, _addr: u32, _val: u32) -> Result<(), ()> {
-        Err(())
+    pub fn mmio_store(&mut self, addr: u32, val: u32) -> Result<(), ()> {
+        match addr {
+            // Setting the alarm is a two-step process: firmware writes the
+            // higher word first (which gets staged) and then the lower word,
+            // which arms the alarm - this way we never end up with a
+            // half-written alarm that could go off too early.
+            const { AliveBot::MEM_TIMER + 12 } => {
+                self.alarm = Some(((self.alarm_hi as u64) << 32) | val as u64);
+
+                Ok(())
+            }
+
+            const { AliveBot::MEM_TIMER + 16 } => {
+                self.alarm_hi = val;
+
+                Ok(())
+            }
+
+            const { AliveBot::MEM_TIMER + 20 } => {
+                self.alarm = None;
+
+                Ok(())
+            }
+
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks() {
+        let mut target = BotTimer {
+            ticks: u32::MAX as u64,
+            ..Default::default()
+        };
+
+        assert_eq!(Ok(u32::MAX), target.mmio_load(AliveBot::MEM_TIMER + 4));
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_TIMER + 8));
+
+        target.tick();
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_TIMER + 4));
+        assert_eq!(Ok(1), target.mmio_load(AliveBot::MEM_TIMER + 8));
+    }
+
+    #[test]
+    fn alarm() {
+        let mut target = BotTimer::default();
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_TIMER + 12));
+
+        // ---
+
+        target.mmio_store(AliveBot::MEM_TIMER + 16, 1).unwrap();
+        target.mmio_store(AliveBot::MEM_TIMER + 12, 2).unwrap();
+
+        assert_eq!(Some((1 << 32) | 2), target.alarm);
+
+        target.ticks = (1 << 32) | 1;
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_TIMER + 12));
+
+        target.tick();
+
+        assert_eq!(Ok(1), target.mmio_load(AliveBot::MEM_TIMER + 12));
+
+        target.tick();
+
+        assert_eq!(Ok(1), target.mmio_load(AliveBot::MEM_TIMER + 12));
+
+        // ---
+
+        target.mmio_store(AliveBot::MEM_TIMER + 20, 0).unwrap();
+
+        assert_eq!(Ok(0), target.mmio_load(AliveBot::MEM_TIMER + 12));
     }
 }
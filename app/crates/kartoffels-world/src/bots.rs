@@ -1,13 +1,17 @@
 mod alive;
+mod cpu_rng;
 mod dead;
 mod queued;
 mod systems;
 
 pub use self::alive::*;
+pub use self::cpu_rng::*;
 pub use self::dead::*;
 pub use self::queued::*;
 pub use self::systems::*;
-use crate::{AliveBot, BotId, CreateBotRequest, Dir, QueuedBot};
+use crate::{
+    AliveBot, Attacker, BotId, CreateBotRequest, DeathReason, Dir, QueuedBot,
+};
 use anyhow::Result;
 use bevy_ecs::event::Event;
 use bevy_ecs::system::Resource;
@@ -40,6 +44,12 @@ impl Bots {
 pub struct Spawn {
     pub pos: Option<IVec2>,
     pub dir: Option<Dir>,
+
+    /// Index of the zone to be used next by [`crate::SpawnStrategy::RoundRobin`].
+    ///
+    /// Not persisted - after the world gets restarted, the round-robin starts
+    /// over from the first zone.
+    pub next_zone: usize,
 }
 
 #[derive(Debug, Event)]
@@ -58,6 +68,71 @@ pub struct SpawnBot {
 #[derive(Debug, Event)]
 pub struct KillBot {
     pub killed: Option<Box<AliveBot>>,
-    pub reason: String,
-    pub killer: Option<BotId>,
+    pub reason: DeathReason,
+    pub killer: Option<Attacker>,
+    pub msg: String,
+}
+
+impl KillBot {
+    /// Returns the bot responsible for this death.
+    ///
+    /// Bots that have been wounded and then died on their own (e.g. fell into
+    /// the void while running away) count as killed by whoever wounded them
+    /// last - otherwise worlds with more than one hit point would let bots
+    /// deny their opponents the score.
+    pub fn killer(&self) -> Option<Attacker> {
+        self.killer.or_else(|| {
+            let killed = self.killed.as_ref()?;
+
+            match self.reason {
+                DeathReason::FellIntoVoid
+                | DeathReason::Lava
+                | DeathReason::Mine => killed.health.last_hit_by(),
+
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BotHealth, Team};
+
+    #[test]
+    fn killer() {
+        let attacker = |id, team| Attacker {
+            id: BotId::new(id),
+            team: Some(Team::new(team)),
+        };
+
+        let event = |reason, killer| {
+            let mut killed = AliveBot {
+                health: BotHealth::new(2),
+                ..Default::default()
+            };
+
+            killed.health.damage(1, attacker(123, 1));
+
+            KillBot {
+                killed: Some(Box::new(killed)),
+                reason,
+                killer,
+                msg: Default::default(),
+            }
+        };
+
+        assert_eq!(
+            Some(attacker(456, 2)),
+            event(DeathReason::Stabbed, Some(attacker(456, 2))).killer()
+        );
+
+        assert_eq!(
+            Some(attacker(123, 1)),
+            event(DeathReason::FellIntoVoid, None).killer()
+        );
+
+        assert_eq!(None, event(DeathReason::KilledByAdmin, None).killer());
+    }
 }
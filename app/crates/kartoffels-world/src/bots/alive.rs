@@ -1,4 +1,4 @@
-use crate::{AliveBot, BotId};
+use crate::{AliveBot, BotId, Team};
 use ahash::AHashMap;
 use anyhow::Result;
 use glam::IVec2;
@@ -9,6 +9,7 @@ pub struct AliveBots {
     entries: Vec<Option<Box<AliveBot>>>,
     id_to_idx: AHashMap<BotId, u8>,
     pos_to_id: AHashMap<IVec2, BotId>,
+    id_to_team: AHashMap<BotId, Team>,
     count: usize,
 }
 
@@ -22,6 +23,10 @@ impl AliveBots {
                 self.pos_to_id.insert(bot.pos, bot.id);
                 self.count += 1;
 
+                if let Some(team) = bot.team {
+                    self.id_to_team.insert(bot.id, team);
+                }
+
                 *slot = Some(Box::new(bot));
                 return;
             }
@@ -32,8 +37,13 @@ impl AliveBots {
 
         self.id_to_idx.insert(bot.id, idx);
         self.pos_to_id.insert(bot.pos, bot.id);
-        self.entries.push(Some(Box::new(bot)));
         self.count += 1;
+
+        if let Some(team) = bot.team {
+            self.id_to_team.insert(bot.id, team);
+        }
+
+        self.entries.push(Some(Box::new(bot)));
     }
 
     pub fn get(&self, id: BotId) -> Option<&AliveBot> {
@@ -43,24 +53,38 @@ impl AliveBots {
         Some(bot)
     }
 
+    pub fn get_mut(&mut self, id: BotId) -> Option<&mut AliveBot> {
+        let idx = *self.id_to_idx.get(&id)?;
+        let bot = self.entries[idx as usize].as_mut().unwrap();
+
+        Some(bot)
+    }
+
     pub fn remove(&mut self, id: BotId) -> Option<Box<AliveBot>> {
         let idx = self.id_to_idx.remove(&id)?;
         let bot = self.entries[idx as usize].take().unwrap();
 
         self.pos_to_id.remove(&bot.pos).unwrap();
+        self.id_to_team.remove(&id);
         self.count -= 1;
 
         Some(bot)
     }
 
-    pub fn remove_at(&mut self, pos: IVec2) -> Option<Box<AliveBot>> {
-        self.remove(self.lookup_at(pos)?)
-    }
-
     pub fn lookup_at(&self, pos: IVec2) -> Option<BotId> {
         self.pos_to_id.get(&pos).copied()
     }
 
+    /// Returns team of given bot - works also for bots that are currently
+    /// taken out (see: [`Self::take()`]).
+    pub fn team_of(&self, id: BotId) -> Option<Team> {
+        self.id_to_team.get(&id).copied()
+    }
+
+    pub fn idx_of(&self, id: BotId) -> Option<usize> {
+        self.id_to_idx.get(&id).map(|idx| *idx as usize)
+    }
+
     pub fn take(&mut self, idx: usize) -> Option<Box<AliveBot>> {
         self.entries[idx].take()
     }
@@ -82,10 +106,15 @@ impl AliveBots {
         } else {
             self.id_to_idx.remove(&id);
             self.pos_to_id.remove(&pos);
+            self.id_to_team.remove(&id);
             self.count -= 1;
         }
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &AliveBot> {
+        self.entries.iter().flatten().map(|bot| &**bot)
+    }
+
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut AliveBot> {
         self.entries.iter_mut().flatten().map(|bot| &mut **bot)
     }
@@ -0,0 +1,37 @@
+use crate::WorldRng;
+use bevy_ecs::system::Resource;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// Source of randomness for bots' CPUs.
+///
+/// Each bot gets its own stream of random numbers, derived from a seed that's
+/// taken from the world's rng once and then mixed with the tick number - this
+/// way bots can be run in any order (or in parallel) without affecting the
+/// numbers they get.
+///
+/// Both the seed and the tick number are saved together with the world, so
+/// that a resumed world continues the sequence instead of starting anew.
+#[derive(Clone, Debug, Default, Resource, Serialize, Deserialize)]
+pub struct CpuRng {
+    seed: Option<<ChaCha8Rng as SeedableRng>::Seed>,
+    tick: u64,
+}
+
+impl CpuRng {
+    pub fn next(
+        &mut self,
+        rng: &mut WorldRng,
+    ) -> <ChaCha8Rng as SeedableRng>::Seed {
+        let mut seed = *self.seed.get_or_insert_with(|| rng.0.gen());
+
+        for (a, b) in seed.iter_mut().zip(self.tick.to_le_bytes()) {
+            *a ^= b;
+        }
+
+        self.tick += 1;
+
+        seed
+    }
+}
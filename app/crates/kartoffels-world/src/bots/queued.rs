@@ -46,6 +46,10 @@ impl QueuedBots {
         self.index.contains_key(&id)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedBot> {
+        self.entries.iter().map(|bot| &**bot)
+    }
+
     pub fn iter_mut(&mut self) -> impl Iterator<Item = QueuedBotEntryMut> {
         self.entries.iter_mut().enumerate().map(|(idx, bot)| {
             QueuedBotEntryMut {
@@ -114,10 +118,13 @@ mod tests {
             events: Default::default(),
             fw: Default::default(),
             id: BotId::new(id),
+            nvram: Default::default(),
             oneshot: false,
+            owner: None,
             pos: None,
             requeued: false,
             serial: Default::default(),
+            team: None,
         })
     }
 
@@ -1,9 +1,12 @@
+mod behaviours;
 mod create;
 mod kill;
 mod spawn;
+mod terrain;
 mod tick;
 
 pub use self::create::*;
 pub use self::kill::*;
 pub use self::spawn::*;
+pub use self::terrain::*;
 pub use self::tick::*;
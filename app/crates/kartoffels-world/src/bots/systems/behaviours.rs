@@ -0,0 +1,151 @@
+//! Behaviours of objects and tiles that kick in as bots move around.
+
+use crate::{
+    AliveBot, Bots, Clock, DeathReason, Dir, Event, KillBot, Map, ObjectKind,
+    Objects, TileKind,
+};
+use ahash::AHashSet;
+use bevy_ecs::system::Commands;
+use glam::IVec2;
+
+/// Opens the door at given position, provided the bot carries a key.
+///
+/// Keys are single-use - opening a door consumes the key.
+pub fn open_door(
+    cmds: &mut Commands,
+    clock: &Clock,
+    map: &mut Map,
+    bot: &mut AliveBot,
+    at: IVec2,
+) -> bool {
+    if bot.inventory.take_kind(ObjectKind::KEY).is_none() {
+        return false;
+    }
+
+    map.get_mut(at).kind = TileKind::DOOR_OPEN;
+
+    bot.log(
+        clock,
+        format!("opened door at {},{} (key used up)", at.x, at.y),
+    );
+
+    cmds.send_event(Event::DoorOpened {
+        at,
+        by: Some(bot.id),
+    });
+    cmds.send_event(Event::TileChanged { at });
+
+    true
+}
+
+/// If the bot stands on a teleporter, moves it to the next free teleporter on
+/// the same channel.
+///
+/// Teleporters on a channel are ordered by their ids, so that the destination
+/// doesn't depend on the order in which objects are stored.
+///
+/// `vacated` contains tiles left by bots during the current tick - those are
+/// not considered free.
+pub fn teleport(
+    cmds: &mut Commands,
+    clock: &Clock,
+    bots: &Bots,
+    objects: &Objects,
+    vacated: &mut AHashSet<IVec2>,
+    bot: &mut AliveBot,
+) {
+    let Some(src_id) = objects.lookup_at(bot.pos) else {
+        return;
+    };
+
+    // Unwrap-safety: We've just found this object
+    let src = objects.get(src_id).unwrap();
+
+    if src.kind != ObjectKind::TELEPORTER {
+        return;
+    }
+
+    let mut pads: Vec<_> = objects
+        .iter()
+        .filter(|entry| {
+            entry.obj.kind == ObjectKind::TELEPORTER
+                && entry.obj.channel() == src.channel()
+        })
+        .filter_map(|entry| Some((entry.id, entry.pos?)))
+        .collect();
+
+    pads.sort_by_key(|(id, _)| *id);
+
+    // Unwrap-safety: The source teleporter is on the list
+    let src_idx = pads.iter().position(|(id, _)| *id == src_id).unwrap();
+
+    let dst = pads
+        .iter()
+        .cycle()
+        .skip(src_idx + 1)
+        .take(pads.len() - 1)
+        .map(|(_, pos)| *pos)
+        .find(|pos| {
+            bots.alive.lookup_at(*pos).is_none() && !vacated.contains(pos)
+        });
+
+    let Some(dst) = dst else {
+        return;
+    };
+
+    let src = bot.pos;
+
+    vacated.insert(src);
+    bot.pos = dst;
+
+    bot.log(
+        clock,
+        format!("teleported from {},{} to {},{}", src.x, src.y, dst.x, dst.y),
+    );
+
+    cmds.send_event(Event::BotTeleported {
+        id: bot.id,
+        from: src,
+        to: dst,
+    });
+}
+
+/// Explodes the first mine found next to the bot, killing it.
+pub fn trigger_mines(
+    cmds: &mut Commands,
+    objects: &mut Objects,
+    vacated: &mut AHashSet<IVec2>,
+    bot: Box<AliveBot>,
+) -> Option<Box<AliveBot>> {
+    for dir in Dir::all() {
+        let at = bot.pos + dir.as_vec();
+
+        let Some(id) = objects.lookup_at(at) else {
+            continue;
+        };
+
+        if objects.get(id).unwrap().kind != ObjectKind::MINE {
+            continue;
+        }
+
+        objects.remove(id);
+        vacated.insert(bot.pos);
+
+        cmds.send_event(Event::MineExploded {
+            id,
+            at,
+            victim: bot.id,
+        });
+
+        cmds.send_event(KillBot {
+            killed: Some(bot),
+            reason: DeathReason::Mine,
+            killer: None,
+            msg: format!("blown up by a mine at {},{}", at.x, at.y),
+        });
+
+        return None;
+    }
+
+    Some(bot)
+}
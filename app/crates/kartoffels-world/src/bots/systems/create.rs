@@ -1,6 +1,6 @@
 use crate::{
     BotEvents, Bots, Clock, CreateBot, CreateBotRequest, Policy, QueuedBot,
-    SpawnBot, WorldRng,
+    SpawnBot, Team, WorldRng,
 };
 use anyhow::{anyhow, Context};
 use bevy_ecs::event::EventMutator;
@@ -28,6 +28,8 @@ pub fn create(
             dir,
             instant,
             oneshot,
+            owner,
+            team,
         } = req;
 
         debug!(
@@ -36,9 +38,26 @@ pub fn create(
             ?dir,
             ?instant,
             ?oneshot,
+            ?owner,
+            ?team,
             "creating bot",
         );
 
+        if let Some(team) = team
+            && team.get() >= policy.teams
+        {
+            _ = tx.send(Err(if policy.teams == 0 {
+                anyhow!("world doesn't have teams")
+            } else {
+                anyhow!(
+                    "team {team} doesn't exist, world has {} teams",
+                    policy.teams
+                )
+            }));
+
+            continue;
+        }
+
         let events = {
             let mut events = BotEvents::default();
 
@@ -71,10 +90,13 @@ pub fn create(
             events,
             fw,
             id,
+            nvram: Default::default(),
             oneshot,
+            owner,
             pos,
             requeued: false,
             serial: Default::default(),
+            team: team.or_else(|| smallest_team(&bots, &policy)),
         });
 
         if instant {
@@ -98,3 +120,25 @@ pub fn create(
         }
     }
 }
+
+fn smallest_team(bots: &Bots, policy: &Policy) -> Option<Team> {
+    let mut sizes = vec![0; policy.teams as usize];
+
+    let teams = bots
+        .alive
+        .iter()
+        .filter_map(|bot| bot.team)
+        .chain(bots.queued.iter().filter_map(|bot| bot.team));
+
+    for team in teams {
+        if let Some(size) = sizes.get_mut(team.get() as usize) {
+            *size += 1;
+        }
+    }
+
+    sizes
+        .iter()
+        .enumerate()
+        .min_by_key(|(idx, size)| (**size, *idx))
+        .map(|(idx, _)| Team::new(idx as u8))
+}
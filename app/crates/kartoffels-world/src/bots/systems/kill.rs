@@ -1,20 +1,31 @@
-use crate::{Bots, Clock, DeadBot, Event, KillBot, Policy, QueuedBot};
+use crate::{
+    Bots, Clock, DeadBot, Event, FlagHomes, KillBot, Map, ObjectKind, Objects,
+    Policy, QueuedBot, WorldRng,
+};
 use bevy_ecs::event::EventMutator;
 use bevy_ecs::system::{Commands, Res, ResMut};
 use tracing::trace;
 
+#[allow(clippy::too_many_arguments)]
 pub fn kill(
     mut cmds: Commands,
     clock: Res<Clock>,
     policy: Res<Policy>,
+    map: Res<Map>,
+    flag_homes: Res<FlagHomes>,
     mut bots: ResMut<Bots>,
+    mut objects: ResMut<Objects>,
+    mut rng: ResMut<WorldRng>,
     mut events: EventMutator<KillBot>,
 ) {
     for event in events.read() {
+        let killer = event.killer().map(|killer| killer.id);
+
         let KillBot {
             killed,
             reason,
-            killer,
+            msg,
+            ..
         } = event;
 
         let mut killed = *killed
@@ -26,13 +37,25 @@ pub fn kill(
         cmds.send_event(Event::BotDied {
             id: killed.id,
             age: killed.age(),
+            reason: *reason,
+            killer,
         });
 
-        if let Some(id) = killer {
-            cmds.send_event(Event::BotScored { id: *id });
-        }
+        killed.log(&clock, &*msg);
 
-        killed.log(&clock, &*reason);
+        // Flags are shared by everyone, so instead of disappearing together
+        // with the bot, they go back to where they were lying originally
+        while let Some((id, obj)) = killed.inventory.take_kind(ObjectKind::FLAG)
+        {
+            flag_homes.put_back(
+                &mut rng.0,
+                &map,
+                &bots.alive,
+                &mut objects,
+                id,
+                obj,
+            );
+        }
 
         let decision = if !killed.oneshot
             && policy.auto_respawn
@@ -52,10 +75,13 @@ pub fn kill(
                     events: killed.events,
                     fw: killed.fw,
                     id: killed.id,
+                    nvram: killed.nvram,
                     oneshot: false,
+                    owner: killed.owner,
                     pos: None,
                     requeued: true,
                     serial: killed.serial,
+                    team: killed.team,
                 }));
             }
 
@@ -79,3 +105,62 @@ enum Decision {
     Requeue,
     Discard,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AliveBot, DeathReason, Object, ObjectId, TileKind};
+    use bevy_ecs::event::EventRegistry;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_ecs::world::World;
+    use glam::{ivec2, uvec2};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn flags_go_back_home() {
+        let mut world = World::new();
+        let mut map = Map::new(uvec2(5, 5));
+        let mut killed = AliveBot::default();
+        let mut flag_homes = FlagHomes::default();
+        let flag = ObjectId::new(123);
+
+        map.fill(TileKind::FLOOR);
+        flag_homes.0.insert(flag, ivec2(3, 4));
+
+        killed
+            .inventory
+            .add(flag, Object::new(ObjectKind::FLAG))
+            .unwrap();
+
+        world.insert_resource(Bots::default());
+        world.insert_resource(Clock::manual());
+        world.insert_resource(flag_homes);
+        world.insert_resource(map);
+        world.insert_resource(Objects::default());
+        world.insert_resource(Policy::default());
+        world.insert_resource(WorldRng(ChaCha8Rng::from_seed(
+            Default::default(),
+        )));
+
+        EventRegistry::register_event::<Event>(&mut world);
+        EventRegistry::register_event::<KillBot>(&mut world);
+
+        world.send_event(KillBot {
+            killed: Some(Box::new(killed)),
+            reason: DeathReason::Stabbed,
+            killer: None,
+            msg: Default::default(),
+        });
+
+        world.run_system_once(kill).unwrap();
+
+        assert_eq!(
+            Some(ObjectKind::FLAG),
+            world
+                .resource::<Objects>()
+                .get_at(ivec2(3, 4))
+                .map(|obj| obj.kind)
+        );
+    }
+}
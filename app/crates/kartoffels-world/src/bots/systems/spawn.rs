@@ -1,11 +1,13 @@
 use crate::{
     AliveBot, AliveBots, Bots, Clock, Dir, Event, Map, Objects, Policy,
-    QueuedBot, Spawn, SpawnBot, WorldRng,
+    QueuedBot, Spawn, SpawnBot, SpawnPolicy, SpawnStrategy, SpawnZone, Team,
+    WorldRng,
 };
 use anyhow::anyhow;
 use bevy_ecs::event::EventMutator;
 use bevy_ecs::system::{Commands, Res, ResMut};
 use glam::{IVec2, UVec2};
+use rand::seq::SliceRandom;
 use rand::{Rng, RngCore};
 use tracing::trace;
 
@@ -36,8 +38,9 @@ pub fn spawn(
     clock: Res<Clock>,
     map: Res<Map>,
     objects: Res<Objects>,
+    policy: Res<Policy>,
     mut rng: ResMut<WorldRng>,
-    spawn: Res<Spawn>,
+    mut spawn: ResMut<Spawn>,
     mut events: EventMutator<SpawnBot>,
 ) {
     for event in events.read() {
@@ -51,7 +54,8 @@ pub fn spawn(
             &map,
             &bots.alive,
             &objects,
-            &spawn,
+            &policy,
+            &mut spawn,
             &bot,
         ) else {
             if let Some(tx) = event.tx.take() {
@@ -65,12 +69,13 @@ pub fn spawn(
             continue;
         };
 
-        let bot = AliveBot::new(&mut rng.0, &clock, pos, dir, *bot);
+        let bot = AliveBot::new(&mut rng.0, &clock, &policy, pos, dir, *bot);
         let id = bot.id;
+        let team = bot.team;
 
         trace!(?id, ?pos, ?dir, "spawning bot");
 
-        cmds.send_event(Event::BotBorn { id });
+        cmds.send_event(Event::BotBorn { id, team });
         bots.alive.add(bot);
 
         if let Some(tx) = event.tx.take() {
@@ -84,7 +89,8 @@ fn determine_spawn_point(
     map: &Map,
     bots: &AliveBots,
     objs: &Objects,
-    spawn: &Spawn,
+    policy: &Policy,
+    spawn: &mut Spawn,
     bot: &QueuedBot,
 ) -> Option<(IVec2, Dir)> {
     if let Some(pos) = bot.pos {
@@ -107,9 +113,92 @@ fn determine_spawn_point(
         };
     }
 
+    if !policy.spawn.is_empty() {
+        let dir = bot.dir.unwrap_or_else(|| rng.gen());
+
+        return sample_zones(
+            rng,
+            map,
+            bots,
+            objs,
+            &policy.spawn,
+            spawn,
+            bot.team,
+        )
+        .map(|pos| (pos, dir));
+    }
+
     sample_map(rng, map, bots, objs, bot)
 }
 
+fn sample_zones(
+    rng: &mut impl RngCore,
+    map: &Map,
+    bots: &AliveBots,
+    objs: &Objects,
+    policy: &SpawnPolicy,
+    spawn: &mut Spawn,
+    team: Option<Team>,
+) -> Option<IVec2> {
+    let candidates = |zone: &SpawnZone| -> Vec<IVec2> {
+        zone.positions(map.size())
+            .filter(|pos| is_pos_legal(map, bots, objs, *pos))
+            .collect()
+    };
+
+    let mut random = |mut zones: Vec<&SpawnZone>| {
+        zones.shuffle(rng);
+
+        zones
+            .into_iter()
+            .find_map(|zone| candidates(zone).choose(rng).copied())
+    };
+
+    match policy.strategy {
+        SpawnStrategy::Random => random(policy.zones.iter().collect()),
+
+        SpawnStrategy::RoundRobin => {
+            let len = policy.zones.len();
+
+            (0..len).find_map(|offset| {
+                let idx = (spawn.next_zone + offset) % len;
+                let pos =
+                    candidates(&policy.zones[idx]).choose(rng).copied()?;
+
+                spawn.next_zone = (idx + 1) % len;
+
+                Some(pos)
+            })
+        }
+
+        SpawnStrategy::Farthest => {
+            let mut candidates: Vec<_> =
+                policy.zones.iter().flat_map(candidates).collect();
+
+            // Shuffle, so that ties (e.g. when there are no other bots) get
+            // resolved randomly
+            candidates.shuffle(rng);
+
+            candidates.into_iter().max_by_key(|pos| {
+                bots.iter()
+                    .map(|bot| bot.pos.distance_squared(*pos))
+                    .min()
+                    .unwrap_or(0)
+            })
+        }
+
+        SpawnStrategy::PerTeam => {
+            let zones = policy
+                .zones
+                .iter()
+                .filter(|zone| team.is_some() && zone.team == team)
+                .collect();
+
+            random(zones).or_else(|| random(policy.zones.iter().collect()))
+        }
+    }
+}
+
 fn sample_map(
     rng: &mut impl RngCore,
     map: &Map,
@@ -0,0 +1,351 @@
+use super::behaviours;
+use crate::{
+    AliveBots, BotId, Bots, Clock, DeathReason, Event, KillBot, Map, Objects,
+    TileKind,
+};
+use ahash::{AHashMap, AHashSet};
+use bevy_ecs::event::EventReader;
+use bevy_ecs::system::{Commands, Local, Res, ResMut, Resource};
+use glam::IVec2;
+use serde::{Deserialize, Serialize};
+
+/// How many ticks a bot can spend on lava before burning.
+pub const LAVA_TICKS: u32 = 32_000;
+
+/// How many ticks it takes for a conveyor to push bot by one tile.
+pub const CONVEYOR_TICKS: u32 = 16_000;
+
+/// State of [`terrain()`] - it's persisted, so that restarting the world
+/// doesn't reset bots' exposures or forget about doors held open by plates.
+#[derive(Clone, Debug, Default, Resource, Serialize, Deserialize)]
+pub struct Terrain {
+    exposures: AHashMap<BotId, Exposure>,
+
+    /// Channels whose plates are pressed (`true`) or have been released, but
+    /// whose doors couldn't be closed yet (`false`).
+    plates: AHashMap<u8, bool>,
+
+    /// Whether the map contains any tiles handled by [`terrain()`] - `None`
+    /// means that the map has changed and has to be scanned again.
+    #[serde(skip)]
+    is_active: Option<bool>,
+}
+
+impl Terrain {
+    /// Makes [`terrain()`] scan the map again, see [`Self::is_active()`].
+    pub fn invalidate(&mut self) {
+        self.is_active = None;
+    }
+
+    /// Returns whether there's anything for [`terrain()`] to do, so that
+    /// maps without any lava, conveyors or plates don't pay for them.
+    fn is_active(&mut self, map: &Map) -> bool {
+        let has_tiles = *self.is_active.get_or_insert_with(|| {
+            let mut has_tiles = false;
+
+            map.for_each(|_, tile| {
+                has_tiles |= matches!(
+                    tile.kind,
+                    TileKind::CONVEYOR | TileKind::LAVA | TileKind::PLATE
+                );
+            });
+
+            has_tiles
+        });
+
+        // Doors of released plates might still be waiting to get closed
+        has_tiles || !self.plates.is_empty()
+    }
+}
+
+/// Scratch space for [`terrain()`], kept around between ticks to avoid
+/// allocating.
+#[derive(Default)]
+pub struct TerrainBuffers {
+    burned: Vec<BotId>,
+    pushed: Vec<(BotId, IVec2)>,
+    pressed: AHashMap<u8, BotId>,
+    vacated: AHashSet<IVec2>,
+}
+
+/// Applies effects of tiles bots are standing on, see [`TileKind::LAVA`],
+/// [`TileKind::CONVEYOR`] and [`TileKind::PLATE`] - [`TileKind::WATER`] is
+/// handled by the motor.
+///
+/// Each bot has a single exposure counter that gets reset as soon as the bot
+/// steps onto a different kind of tile - hopping between two lava tiles
+/// doesn't help.
+///
+/// Bots pushed by conveyors are subject to the same behaviours as bots that
+/// have moved on their own, i.e. they can get teleported or blown up by mines.
+#[allow(clippy::too_many_arguments)]
+pub fn terrain(
+    mut cmds: Commands,
+    clock: Res<Clock>,
+    mut map: ResMut<Map>,
+    mut objects: ResMut<Objects>,
+    mut bots: ResMut<Bots>,
+    mut terrain: ResMut<Terrain>,
+    mut bufs: Local<TerrainBuffers>,
+    mut events: EventReader<Event>,
+) {
+    if events
+        .read()
+        .any(|event| matches!(event, Event::TileChanged { .. }))
+    {
+        terrain.invalidate();
+    }
+
+    if !terrain.is_active(&map) {
+        return;
+    }
+
+    let Terrain {
+        exposures, plates, ..
+    } = &mut *terrain;
+
+    let TerrainBuffers {
+        burned,
+        pushed,
+        pressed,
+        vacated,
+    } = &mut *bufs;
+
+    vacated.clear();
+
+    press_plates(&mut cmds, &mut map, &objects, &bots.alive, plates, pressed);
+
+    let ticks = clock.ticks();
+
+    exposures.retain(|id, exposure| {
+        bots.alive
+            .get(*id)
+            .is_some_and(|bot| map.get(bot.pos).kind == exposure.kind)
+    });
+
+    for bot in bots.alive.iter() {
+        let tile = map.get(bot.pos);
+
+        if tile.kind != TileKind::LAVA && tile.kind != TileKind::CONVEYOR {
+            continue;
+        }
+
+        let exposure = exposures.entry(bot.id).or_insert(Exposure {
+            kind: tile.kind,
+            ticks: 0,
+        });
+
+        exposure.ticks += ticks;
+
+        if let Some(dir) = tile.conveyor_dir() {
+            if exposure.ticks >= CONVEYOR_TICKS {
+                exposure.ticks = 0;
+                pushed.push((bot.id, bot.pos + dir));
+            }
+        } else if exposure.ticks >= LAVA_TICKS {
+            burned.push(bot.id);
+        }
+    }
+
+    for id in burned.drain(..) {
+        exposures.remove(&id);
+
+        if let Some(bot) = bots.alive.remove(id) {
+            cmds.send_event(KillBot {
+                killed: Some(bot),
+                reason: DeathReason::Lava,
+                killer: None,
+                msg: "burned in lava".into(),
+            });
+        }
+    }
+
+    for (id, at) in pushed.drain(..) {
+        let tile = map.get(at);
+
+        if tile.is_void() {
+            exposures.remove(&id);
+
+            if let Some(bot) = bots.alive.remove(id) {
+                cmds.send_event(KillBot {
+                    killed: Some(bot),
+                    reason: DeathReason::FellIntoVoid,
+                    killer: None,
+                    msg: "fell into the void".into(),
+                });
+            }
+
+            continue;
+        }
+
+        let is_free = tile.is_walkable()
+            && bots.alive.lookup_at(at).is_none()
+            && objects.get_at(at).is_none_or(|obj| obj.is_walkable());
+
+        if !is_free {
+            continue;
+        }
+
+        let Some(idx) = bots.alive.idx_of(id) else {
+            continue;
+        };
+
+        // Unwrap-safety: We've just found this bot
+        let mut bot = bots.alive.take(idx).unwrap();
+        let pos = bot.pos;
+
+        vacated.insert(pos);
+        bot.pos = at;
+
+        cmds.send_event(Event::BotMoved { id, at });
+
+        behaviours::teleport(
+            &mut cmds, &clock, &bots, &objects, vacated, &mut bot,
+        );
+
+        let bot =
+            behaviours::trigger_mines(&mut cmds, &mut objects, vacated, bot);
+
+        if bot.is_none() {
+            exposures.remove(&id);
+        }
+
+        bots.alive.insert(idx, id, pos, bot);
+    }
+}
+
+/// Opens doors of plates that have just been pressed and closes doors of
+/// plates that have been released.
+///
+/// `plates` keeps track of channels that are pressed (`true`) or that have
+/// been released, but whose doors couldn't be closed yet (`false`).
+fn press_plates(
+    cmds: &mut Commands,
+    map: &mut Map,
+    objects: &Objects,
+    bots: &AliveBots,
+    plates: &mut AHashMap<u8, bool>,
+    pressed: &mut AHashMap<u8, BotId>,
+) {
+    pressed.clear();
+
+    for bot in bots.iter() {
+        let tile = map.get(bot.pos);
+
+        if tile.kind == TileKind::PLATE {
+            pressed.entry(tile.channel()).or_insert(bot.id);
+        }
+    }
+
+    for (&channel, &id) in pressed.iter() {
+        if plates.insert(channel, true) != Some(true) {
+            set_doors(cmds, map, objects, bots, channel, true, Some(id));
+        }
+    }
+
+    plates.retain(|channel, is_pressed| {
+        if pressed.contains_key(channel) {
+            return true;
+        }
+
+        *is_pressed = false;
+
+        !set_doors(cmds, map, objects, bots, *channel, false, None)
+    });
+}
+
+/// Opens or closes all doors on given channel, returning whether all of them
+/// are now in the requested state.
+///
+/// Channel zero is not wired to any doors, so it's a no-op.
+///
+/// See: [`set_door()`].
+pub(crate) fn set_doors(
+    cmds: &mut Commands,
+    map: &mut Map,
+    objects: &Objects,
+    bots: &AliveBots,
+    channel: u8,
+    open: bool,
+    by: Option<BotId>,
+) -> bool {
+    if channel == 0 {
+        return true;
+    }
+
+    let mut doors = Vec::new();
+
+    map.for_each(|pos, tile| {
+        if tile.is_door() && tile.channel() == channel {
+            doors.push(pos);
+        }
+    });
+
+    doors.into_iter().fold(true, |done, at| {
+        set_door(cmds, map, objects, bots, at, open, by) && done
+    })
+}
+
+/// Returns whether doors on given channel are controlled by any switch or
+/// pressure plate.
+pub(crate) fn is_wired(map: &Map, channel: u8) -> bool {
+    let mut wired = false;
+
+    if channel != 0 {
+        map.for_each(|_, tile| {
+            wired |= (tile.kind == TileKind::SWITCH
+                || tile.kind == TileKind::PLATE)
+                && tile.channel() == channel;
+        });
+    }
+
+    wired
+}
+
+/// Opens or closes door at given position, returning whether the door is now
+/// in the requested state.
+///
+/// Door with a bot or an object in the doorway can't be closed.
+pub(crate) fn set_door(
+    cmds: &mut Commands,
+    map: &mut Map,
+    objects: &Objects,
+    bots: &AliveBots,
+    at: IVec2,
+    open: bool,
+    by: Option<BotId>,
+) -> bool {
+    let tile = map.get_mut(at);
+
+    if !tile.is_door() {
+        return false;
+    }
+
+    if (tile.kind == TileKind::DOOR_OPEN) == open {
+        return true;
+    }
+
+    if open {
+        tile.kind = TileKind::DOOR_OPEN;
+
+        cmds.send_event(Event::DoorOpened { at, by });
+    } else {
+        if bots.lookup_at(at).is_some() || objects.lookup_at(at).is_some() {
+            return false;
+        }
+
+        tile.kind = TileKind::DOOR;
+
+        cmds.send_event(Event::DoorClosed { at, by });
+    }
+
+    cmds.send_event(Event::TileChanged { at });
+
+    true
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Exposure {
+    kind: u8,
+    ticks: u32,
+}
@@ -1,55 +1,329 @@
+use super::behaviours;
 use crate::{
-    AliveBot, BotAction, Bots, Clock, Event, KillBot, Map, Objects, TileKind,
-    WorldRng,
+    cfg, AliveBot, AliveBots, Attacker, BotAction, BotArm, BotBattery, BotId,
+    BotMotor, Bots, Clock, CpuRng, DeathReason, Event, FirmwareFault, KillBot,
+    Map, Markers, MoveBlocker, Object, ObjectKind, Objects, Policy, TileKind,
+    WorldRng, WorldThreads,
 };
+use ahash::AHashSet;
 use bevy_ecs::system::{Commands, Res, ResMut};
+use glam::IVec2;
+use kartoffels_cpu::TickError;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
 
+/// Ticks all alive bots.
+///
+/// Each tick happens in two phases - first we run every bot's CPU, collecting
+/// the actions they've requested, and then we resolve those actions all at
+/// once, so that the outcome doesn't depend on the order in which bots are
+/// stored.
+///
+/// Actions are resolved in [`Phase`] order; within a phase, ties are broken
+/// randomly.
+#[allow(clippy::too_many_arguments)]
 pub fn tick(
     mut cmds: Commands,
     clock: Res<Clock>,
-    map: Res<Map>,
+    mut map: ResMut<Map>,
+    mut markers: ResMut<Markers>,
     mut bots: ResMut<Bots>,
     mut objects: ResMut<Objects>,
+    policy: Res<Policy>,
     mut rng: ResMut<WorldRng>,
+    mut cpu_rng: ResMut<CpuRng>,
+    threads: Res<WorldThreads>,
 ) {
+    let mut cpus = Vec::new();
+    let mut actions = Vec::new();
+    let mut state = State::default();
+
     for _ in 0..clock.ticks() {
-        let mut idx = 0;
-        let len = bots.alive.len();
+        if bots.alive.count() == 0 {
+            break;
+        }
+
+        // Bots are taken out of `bots.alive` for the duration of the first
+        // phase, which allows for running them in parallel; the positions
+        // remain indexed, so that radars can still see everybody
+        cpus.extend((0..bots.alive.len()).map(|idx| bots.alive.take(idx)));
+
+        run_cpus(
+            &mut cpus,
+            &bots.alive,
+            &map,
+            &markers,
+            &objects,
+            &policy,
+            &threads,
+            cpu_rng.next(&mut rng),
+            &mut actions,
+        );
+
+        for (idx, bot) in cpus.drain(..).enumerate() {
+            if let Some(bot) = bot {
+                bots.alive.insert(idx, bot.id, bot.pos, Some(bot));
+            }
+        }
+
+        actions.shuffle(&mut rng.0);
+        actions.sort_by_key(|(_, action)| Phase::of(action));
+
+        for (idx, action) in actions.drain(..) {
+            if Phase::of(&action) > Phase::Stab {
+                state.bury(&mut cmds, &mut bots);
+            }
 
-        while idx < len {
             if let Some(bot) = bots.alive.take(idx) {
                 let id = bot.id;
                 let pos = bot.pos;
 
-                let bot = tick_bot(
+                let bot = resolve(
                     &mut cmds,
                     &clock,
-                    &map,
+                    &mut map,
+                    &mut markers,
                     &mut bots,
                     &mut objects,
                     &mut rng,
+                    &mut state,
                     bot,
+                    action,
                 );
 
                 bots.alive.insert(idx, id, pos, bot);
             }
+        }
+
+        state.bury(&mut cmds, &mut bots);
+        state.vacated.clear();
+    }
+
+    // Serial output changes way too often to report each write separately,
+    // so we emit at most one event per bot per frame
+    for bot in bots.alive.iter_mut() {
+        if bot.serial.take_written() {
+            cmds.send_event(Event::BotSerialWritten { id: bot.id });
+        }
+    }
+}
+
+type PendingAction = (usize, Result<BotAction, TickError>);
+
+/// Runs bots' CPUs, collecting the actions they've requested.
+///
+/// Large worlds get partitioned into chunks of [`cfg::BOTS_PER_THREAD`] bots
+/// and run on the shared [`WorldThreads`] pool - since each bot gets its own
+/// random number stream, derived from `seed` and bot's index, the outcome is
+/// the same no matter how many threads we've used.
+#[allow(clippy::too_many_arguments)]
+fn run_cpus(
+    cpus: &mut [Option<Box<AliveBot>>],
+    bots: &AliveBots,
+    map: &Map,
+    markers: &Markers,
+    objects: &Objects,
+    policy: &Policy,
+    threads: &WorldThreads,
+    seed: <ChaCha8Rng as SeedableRng>::Seed,
+    actions: &mut Vec<PendingAction>,
+) {
+    let run = |offset: usize,
+               cpus: &mut [Option<Box<AliveBot>>],
+               actions: &mut Vec<PendingAction>| {
+        for (idx, bot) in cpus.iter_mut().enumerate() {
+            let Some(bot) = bot else {
+                continue;
+            };
+
+            let idx = offset + idx;
+            let mut rng = ChaCha8Rng::from_seed(seed);
+
+            // Note that this doesn't cause the rng to generate any numbers yet,
+            // which is important, since most bots don't use the rng during most
+            // ticks
+            rng.set_stream(idx as u64);
+
+            match bot.tick(bots, map, markers, objects, policy, &mut rng) {
+                Ok(None) => (),
+                Ok(Some(action)) => actions.push((idx, Ok(action))),
+                Err(err) => actions.push((idx, Err(err))),
+            }
+        }
+    };
+
+    let chunks = (cpus.len() / cfg::BOTS_PER_THREAD).min(threads.get());
+
+    if chunks <= 1 {
+        run(0, cpus, actions);
+        return;
+    }
+
+    let chunk = cpus.len().div_ceil(chunks);
+
+    let chunks: Vec<_> = threads.pool().install(|| {
+        cpus.par_chunks_mut(chunk)
+            .enumerate()
+            .map(|(idx, cpus)| {
+                let mut actions = Vec::new();
+
+                run(idx * chunk, cpus, &mut actions);
+                actions
+            })
+            .collect()
+    });
+
+    // Collecting preserves chunks' order, which keeps the actions sorted by
+    // bots' indices, same as if we've run everything on a single thread
+    actions.extend(chunks.into_iter().flatten());
+}
+
+/// Order in which actions get resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Phase {
+    /// Firmware crashes go first, so that crashed bots don't participate in
+    /// the rest of the tick.
+    Crash,
+
+    /// Stabs land against the positions bots had at the beginning of the tick
+    /// and kill simultaneously - two bots can stab each other to death.
+    Stab,
+
+    /// Gives land against the positions bots had at the beginning of the tick
+    /// as well - a bot that moves onto the tile during the same tick doesn't
+    /// receive anything.
+    Give,
+
+    /// Bots can't move onto a tile that was occupied at the beginning of the
+    /// tick, even if its occupant moves away during the same tick; when many
+    /// bots try to move onto the same tile, a random one wins.
+    Move,
+
+    Other,
+}
+
+impl Phase {
+    fn of(action: &Result<BotAction, TickError>) -> Self {
+        match action {
+            Err(_) => Phase::Crash,
+            Ok(BotAction::ArmStab { .. }) => Phase::Stab,
+            Ok(BotAction::ArmGive { .. }) => Phase::Give,
+            Ok(BotAction::MotorMove { .. }) => Phase::Move,
+            Ok(_) => Phase::Other,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Bots stabbed to death, together with their killers.
+    dying: Vec<(BotId, Attacker)>,
+
+    /// Tiles left by bots during the current tick.
+    vacated: AHashSet<IVec2>,
+}
+
+impl State {
+    fn bury(&mut self, cmds: &mut Commands, bots: &mut Bots) {
+        for (id, killer) in self.dying.drain(..) {
+            if let Some(killed) = bots.alive.remove(id) {
+                self.vacated.insert(killed.pos);
 
-            idx += 1;
+                cmds.send_event(KillBot {
+                    killed: Some(killed),
+                    reason: DeathReason::Stabbed,
+                    killer: Some(killer),
+                    msg: format!("killed by {} (knife)", killer.id),
+                });
+            }
         }
     }
 }
 
-fn tick_bot(
+#[allow(clippy::too_many_arguments)]
+fn resolve(
     cmds: &mut Commands,
     clock: &Clock,
-    map: &Map,
+    map: &mut Map,
+    markers: &mut Markers,
     bots: &mut Bots,
     objects: &mut Objects,
     rng: &mut WorldRng,
+    state: &mut State,
     mut bot: Box<AliveBot>,
+    action: Result<BotAction, TickError>,
 ) -> Option<Box<AliveBot>> {
-    match bot.tick(&bots.alive, map, objects, rng) {
-        Ok(Some(BotAction::ArmDrop { at, idx })) => {
+    match action {
+        Ok(BotAction::ArmBuild { at, idx }) => {
+            if let Some(obj) = bot.inventory.get(idx) {
+                if obj.kind != ObjectKind::BRICK {
+                    bot.log(
+                        clock,
+                        format!(
+                            "failed to build with {} (not a brick)",
+                            obj.name()
+                        ),
+                    );
+                } else if !map.get(at).is_floor()
+                    || bots.alive.lookup_at(at).is_some()
+                    || objects.lookup_at(at).is_some()
+                {
+                    bot.log(
+                        clock,
+                        format!(
+                            "failed to build at {},{} (tile occupied)",
+                            at.x, at.y
+                        ),
+                    );
+                } else {
+                    bot.inventory.take(idx);
+                    map.set(at, TileKind::WALL);
+
+                    bot.log(clock, format!("built wall at {},{}", at.x, at.y));
+
+                    cmds.send_event(Event::TileChanged { at });
+                }
+            } else {
+                bot.log(clock, "built nothing");
+            }
+        }
+
+        Ok(BotAction::ArmDig { at }) => {
+            if !map.get(at).is_wall() {
+                bot.log(clock, "dug fresh air");
+            } else if bot.inventory.is_full() {
+                bot.log(
+                    clock,
+                    format!("failed to dig {},{} (inventory full)", at.x, at.y),
+                );
+            } else {
+                // We're resolving this bot's action, so it's not present in
+                // `bots.alive` - its inventory has to be checked separately
+                let id = loop {
+                    let id = objects.create_id(&mut rng.0, &bots.alive);
+
+                    if !bot.inventory.contains(id) {
+                        break id;
+                    }
+                };
+
+                // Unwrap-safety: We've just checked that the inventory is not
+                // full
+                bot.inventory
+                    .add(id, Object::new(ObjectKind::BRICK))
+                    .unwrap();
+                map.set(at, TileKind::FLOOR);
+
+                bot.log(clock, format!("dug {},{}", at.x, at.y));
+
+                cmds.send_event(Event::TileChanged { at });
+            }
+        }
+
+        Ok(BotAction::ArmDrop { at, idx }) => {
             if let Some((id, obj)) = bot.inventory.take(idx) {
                 bot.log(
                     clock,
@@ -63,35 +337,107 @@ fn tick_bot(
             }
         }
 
-        Ok(Some(BotAction::ArmPick { at })) => {
+        Ok(BotAction::ArmGive { at, idx }) => {
+            if let Some(target) = bots
+                .alive
+                .lookup_at(at)
+                .and_then(|id| bots.alive.get_mut(id))
+            {
+                if target.inventory.is_full() {
+                    bot.log(
+                        clock,
+                        format!(
+                            "failed to give anything to {} (inventory full)",
+                            target.id
+                        ),
+                    );
+                } else if let Some((id, obj)) = bot.inventory.take(idx) {
+                    // Unwrap-safety: We've just checked that the inventory
+                    // is not full
+                    target.inventory.add(id, obj).unwrap();
+
+                    target.log(
+                        clock,
+                        format!("received {} from {}", obj.name(), bot.id),
+                    );
+
+                    bot.log(
+                        clock,
+                        format!("gave {} to {}", obj.name(), target.id),
+                    );
+
+                    cmds.send_event(Event::ObjectGiven {
+                        id,
+                        from: bot.id,
+                        to: target.id,
+                    });
+                } else {
+                    bot.log(clock, format!("gave nothing to {}", target.id));
+                }
+            } else {
+                bot.log(clock, "gave nothing to fresh air");
+            }
+        }
+
+        Ok(BotAction::ArmPick { at }) => {
             if let Some((id, obj)) = objects.remove_at(at) {
-                match bot.inventory.add(id, obj) {
-                    Ok(_) => {
-                        cmds.send_event(Event::ObjectPicked { id });
+                if !obj.is_pickable() {
+                    bot.log(
+                        clock,
+                        format!(
+                            "failed to pick {} from {},{} (can't be picked)",
+                            obj.name(),
+                            at.x,
+                            at.y
+                        ),
+                    );
 
-                        bot.log(
-                            clock,
-                            format!(
-                                "picked {} from {},{}",
-                                obj.name(),
-                                at.x,
-                                at.y
-                            ),
-                        );
-                    }
+                    objects.add(id, obj, Some(at));
+                } else if obj.kind == ObjectKind::BATTERY {
+                    let energy = bot.battery.recharge(BotBattery::RECHARGE);
 
-                    Err(_) => {
-                        bot.log(
-                            clock,
-                            format!(
+                    bot.log(
+                        clock,
+                        format!(
+                            "picked {} from {},{} ({} energy)",
+                            obj.name(),
+                            at.x,
+                            at.y,
+                            energy
+                        ),
+                    );
+
+                    cmds.send_event(Event::ObjectPicked { id });
+                    cmds.send_event(Event::BotRecharged { id: bot.id, energy });
+                } else {
+                    match bot.inventory.add(id, obj) {
+                        Ok(_) => {
+                            cmds.send_event(Event::ObjectPicked { id });
+
+                            bot.log(
+                                clock,
+                                format!(
+                                    "picked {} from {},{}",
+                                    obj.name(),
+                                    at.x,
+                                    at.y
+                                ),
+                            );
+                        }
+
+                        Err(_) => {
+                            bot.log(
+                                clock,
+                                format!(
                                 "failed to pick {} from {},{} (inventory full)",
                                 obj.name(),
                                 at.x,
                                 at.y
                             ),
-                        );
+                            );
 
-                        objects.add(id, obj, Some(at));
+                            objects.add(id, obj, Some(at));
+                        }
                     }
                 }
             } else {
@@ -99,53 +445,262 @@ fn tick_bot(
             }
         }
 
-        Ok(Some(BotAction::ArmStab { at })) => {
-            if let Some(killed) = bots.alive.remove_at(at) {
-                bot.log(clock, format!("killed {} (knife)", killed.id));
+        Ok(BotAction::ArmStab { at }) => {
+            if let Some(target) = bots
+                .alive
+                .lookup_at(at)
+                .and_then(|id| bots.alive.get_mut(id))
+            {
+                let hp = target
+                    .health
+                    .damage(BotArm::STAB_DAMAGE, Attacker::of(&bot));
 
-                cmds.send_event(KillBot {
-                    killed: Some(killed),
-                    reason: format!("killed by {} (knife)", bot.id),
-                    killer: Some(bot.id),
+                cmds.send_event(Event::BotStabbed {
+                    id: bot.id,
+                    target: Some(target.id),
                 });
+
+                let is_dying =
+                    state.dying.iter().any(|(id, _)| *id == target.id);
+
+                if hp == 0 && !is_dying {
+                    bot.log(clock, format!("killed {} (knife)", target.id));
+
+                    state.dying.push((target.id, Attacker::of(&bot)));
+                } else {
+                    target.log(
+                        clock,
+                        format!(
+                            "stabbed by {} (knife, {} hp left)",
+                            bot.id, hp
+                        ),
+                    );
+
+                    bot.log(
+                        clock,
+                        format!(
+                            "stabbed {} (knife, {} hp left)",
+                            target.id, hp
+                        ),
+                    );
+                }
             } else {
                 bot.log(clock, "stabbed fresh air");
+
+                cmds.send_event(Event::BotStabbed {
+                    id: bot.id,
+                    target: None,
+                });
             }
         }
 
-        Ok(Some(BotAction::MotorMove { at })) => match map.get(at).kind {
-            TileKind::VOID => {
-                cmds.send_event(KillBot {
-                    killed: Some(bot),
-                    reason: "fell into the void".into(),
-                    killer: None,
-                });
+        Ok(BotAction::ArmUse { at }) => {
+            let tile = map.get(at);
 
-                return None;
-            }
+            match tile.kind {
+                TileKind::SWITCH => {
+                    let on = !tile.is_on();
+
+                    map.get_mut(at).meta[1] = on as u8;
+
+                    bot.log(
+                        clock,
+                        format!(
+                            "switched {} switch at {},{}",
+                            if on { "on" } else { "off" },
+                            at.x,
+                            at.y
+                        ),
+                    );
 
-            TileKind::FLOOR => {
-                if bots.alive.lookup_at(at).is_none()
-                    && objects.lookup_at(at).is_none()
+                    cmds.send_event(Event::TileChanged { at });
+
+                    crate::set_doors(
+                        cmds,
+                        map,
+                        objects,
+                        &bots.alive,
+                        tile.channel(),
+                        on,
+                        Some(bot.id),
+                    );
+                }
+
+                TileKind::DOOR => {
+                    if !behaviours::open_door(cmds, clock, map, &mut bot, at) {
+                        bot.log(
+                            clock,
+                            format!(
+                                "failed to open door at {},{} (no key)",
+                                at.x, at.y
+                            ),
+                        );
+                    }
+                }
+
+                // Doors that aren't controlled by anything can be opened only
+                // with a key, so letting bots close them would allow for
+                // sealing others off for good
+                TileKind::DOOR_OPEN
+                    if !crate::is_wired(map, tile.channel()) =>
                 {
-                    bot.pos = at;
+                    bot.log(
+                        clock,
+                        format!(
+                            "failed to close door at {},{} (not wired)",
+                            at.x, at.y
+                        ),
+                    );
+                }
 
-                    cmds.send_event(Event::BotMoved { id: bot.id, at });
+                TileKind::DOOR_OPEN => {
+                    if crate::set_door(
+                        cmds,
+                        map,
+                        objects,
+                        &bots.alive,
+                        at,
+                        false,
+                        Some(bot.id),
+                    ) {
+                        bot.log(
+                            clock,
+                            format!("closed door at {},{}", at.x, at.y),
+                        );
+                    } else {
+                        bot.log(
+                            clock,
+                            format!(
+                                "failed to close door at {},{} (doorway \
+                                 blocked)",
+                                at.x, at.y
+                            ),
+                        );
+                    }
                 }
+
+                _ => {
+                    bot.log(clock, "used fresh air");
+                }
+            }
+        }
+
+        Ok(BotAction::MarkerSet { at, value, decays }) => {
+            markers.set(at, value, decays);
+        }
+
+        Ok(BotAction::MotorMove { at }) => {
+            let mut tile = map.get(at).kind;
+
+            if tile == TileKind::DOOR_OPEN
+                || (tile == TileKind::DOOR
+                    && behaviours::open_door(cmds, clock, map, &mut bot, at))
+            {
+                tile = TileKind::FLOOR;
             }
 
-            _ => (),
-        },
+            match tile {
+                TileKind::VOID => {
+                    state.vacated.insert(bot.pos);
 
-        Ok(None) => {
-            //
+                    cmds.send_event(KillBot {
+                        killed: Some(bot),
+                        reason: DeathReason::FellIntoVoid,
+                        killer: None,
+                        msg: "fell into the void".into(),
+                    });
+
+                    return None;
+                }
+
+                TileKind::CONVEYOR
+                | TileKind::FLOOR
+                | TileKind::LAVA
+                | TileKind::PLATE
+                | TileKind::WATER => {
+                    if bots.alive.lookup_at(at).is_some()
+                        || state.vacated.contains(&at)
+                    {
+                        bot.motor.report_blocked(MoveBlocker::Bot);
+
+                        cmds.send_event(Event::BotMoveBlocked {
+                            id: bot.id,
+                            at,
+                            by: MoveBlocker::Bot,
+                        });
+                    } else if objects
+                        .get_at(at)
+                        .is_some_and(|obj| !obj.is_walkable())
+                    {
+                        bot.motor.report_blocked(MoveBlocker::Object);
+
+                        cmds.send_event(Event::BotMoveBlocked {
+                            id: bot.id,
+                            at,
+                            by: MoveBlocker::Object,
+                        });
+                    } else {
+                        state.vacated.insert(bot.pos);
+
+                        bot.pos = at;
+                        bot.motor.report(BotMotor::MOVE_OK);
+
+                        cmds.send_event(Event::BotMoved { id: bot.id, at });
+
+                        behaviours::teleport(
+                            cmds,
+                            clock,
+                            bots,
+                            objects,
+                            &mut state.vacated,
+                            &mut bot,
+                        );
+
+                        return behaviours::trigger_mines(
+                            cmds,
+                            objects,
+                            &mut state.vacated,
+                            bot,
+                        );
+                    }
+                }
+
+                _ => {
+                    bot.motor.report_blocked(MoveBlocker::Wall);
+
+                    cmds.send_event(Event::BotMoveBlocked {
+                        id: bot.id,
+                        at,
+                        by: MoveBlocker::Wall,
+                    });
+                }
+            }
+        }
+
+        Ok(BotAction::MotorTurn { dir }) => {
+            bot.dir = dir;
+
+            cmds.send_event(Event::BotTurned { id: bot.id, dir });
+        }
+
+        Ok(BotAction::RadarScan { range }) => {
+            cmds.send_event(Event::BotScanned {
+                id: bot.id,
+                at: bot.pos,
+                range,
+            });
         }
 
         Err(err) => {
+            state.vacated.insert(bot.pos);
+
             cmds.send_event(KillBot {
                 killed: Some(bot),
-                reason: format!("firmware crashed: {err}"),
+                reason: DeathReason::FirmwareCrashed {
+                    fault: FirmwareFault::classify(&err),
+                },
                 killer: None,
+                msg: format!("firmware crashed: {err}"),
             });
 
             return None;
@@ -154,3 +709,106 @@ fn tick_bot(
 
     Some(bot)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::event::EventRegistry;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_ecs::world::World;
+    use glam::{ivec2, uvec2};
+
+    #[test]
+    fn dead_bots_vacate_their_tiles() {
+        let mut world = World::new();
+
+        EventRegistry::register_event::<Event>(&mut world);
+        EventRegistry::register_event::<KillBot>(&mut world);
+
+        world
+            .run_system_once(|mut cmds: Commands| {
+                let clock = Clock::manual();
+                let mut map = Map::new(uvec2(5, 5));
+                let mut markers = Markers::default();
+                let mut bots = Bots::default();
+                let mut objects = Objects::default();
+                let mut rng =
+                    WorldRng(ChaCha8Rng::from_seed(Default::default()));
+                let mut state = State::default();
+
+                let bot = |id, pos| {
+                    Box::new(AliveBot {
+                        id: BotId::new(id),
+                        pos,
+                        ..Default::default()
+                    })
+                };
+
+                map.fill(TileKind::FLOOR);
+
+                // Bot #1 gets stabbed to death
+                bots.alive.add(*bot(1, ivec2(1, 1)));
+
+                state.dying.push((
+                    BotId::new(1),
+                    Attacker {
+                        id: BotId::new(9),
+                        team: None,
+                    },
+                ));
+
+                state.bury(&mut cmds, &mut bots);
+
+                let mut resolve = |bot, action| {
+                    resolve(
+                        &mut cmds,
+                        &clock,
+                        &mut map,
+                        &mut markers,
+                        &mut bots,
+                        &mut objects,
+                        &mut rng,
+                        &mut state,
+                        bot,
+                        action,
+                    )
+                };
+
+                // Bot #2 crashes
+                assert!(resolve(bot(2, ivec2(3, 3)), Err(TickError::Ebreak))
+                    .is_none());
+
+                // Bots #3 and #4 try to move onto tiles occupied by the dead
+                // bots at the beginning of the tick
+                for (id, pos, at) in [
+                    (3, ivec2(1, 2), ivec2(1, 1)),
+                    (4, ivec2(3, 2), ivec2(3, 3)),
+                ] {
+                    let bot =
+                        resolve(bot(id, pos), Ok(BotAction::MotorMove { at }))
+                            .unwrap();
+
+                    assert_eq!(pos, bot.pos);
+                }
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn gives_resolve_before_moves() {
+        let at = IVec2::ZERO;
+
+        let mut actions = vec![
+            Ok(BotAction::MotorMove { at }),
+            Ok(BotAction::ArmGive { at, idx: 0 }),
+            Ok(BotAction::ArmStab { at }),
+        ];
+
+        actions.sort_by_key(Phase::of);
+
+        assert_eq!(
+            vec![Phase::Stab, Phase::Give, Phase::Move],
+            actions.iter().map(Phase::of).collect::<Vec<_>>(),
+        );
+    }
+}
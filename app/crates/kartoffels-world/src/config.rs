@@ -14,6 +14,7 @@ pub struct Config {
     pub policy: Policy,
     pub seed: Option<<ChaCha8Rng as SeedableRng>::Seed>,
     pub theme: Option<Theme>,
+    pub threads: Option<usize>,
 }
 
 impl Config {
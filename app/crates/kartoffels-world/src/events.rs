@@ -3,20 +3,41 @@ mod systems;
 
 pub use self::stream::*;
 pub use self::systems::*;
-use crate::{BotId, ObjectId, Ticks};
+use crate::{BotId, DeathReason, Dir, MoveBlocker, ObjectId, Team, Ticks};
+use ahash::AHashMap;
 use bevy_ecs::event::Event as BevyEvent;
 use bevy_ecs::system::Resource;
 use glam::IVec2;
+use std::mem::{self, Discriminant};
 use tokio::sync::broadcast;
 
 #[derive(Debug, Resource)]
 pub struct Events {
     pub tx: broadcast::Sender<EventLetter>,
     pub pending: Vec<Event>,
+
+    /// Indices of coalescable events within `pending`, see:
+    /// [`Event::coalesce_key()`].
+    pub coalesced: AHashMap<(Discriminant<Event>, BotId), usize>,
 }
 
 impl Events {
+    pub fn push(&mut self, event: Event) {
+        if let Some(key) = event.coalesce_key() {
+            if let Some(&idx) = self.coalesced.get(&key) {
+                self.pending[idx] = event;
+                return;
+            }
+
+            self.coalesced.insert(key, self.pending.len());
+        }
+
+        self.pending.push(event);
+    }
+
     pub fn send(&mut self, version: u64) {
+        self.coalesced.clear();
+
         for event in self.pending.drain(..) {
             _ = self.tx.send(EventLetter { event, version });
         }
@@ -25,13 +46,103 @@ impl Events {
 
 #[derive(Clone, Copy, Debug, BevyEvent)]
 pub enum Event {
-    BotBorn { id: BotId },
-    BotDied { id: BotId, age: Ticks },
-    BotMoved { id: BotId, at: IVec2 },
-    BotScored { id: BotId },
-    BotDiscarded { id: BotId },
-    ObjectPicked { id: ObjectId },
-    ObjectDropped { id: ObjectId },
+    BotBorn {
+        id: BotId,
+        team: Option<Team>,
+    },
+    BotDied {
+        id: BotId,
+        age: Ticks,
+        reason: DeathReason,
+        killer: Option<BotId>,
+    },
+    BotMoved {
+        id: BotId,
+        at: IVec2,
+    },
+    BotMoveBlocked {
+        id: BotId,
+        at: IVec2,
+        by: MoveBlocker,
+    },
+    BotTurned {
+        id: BotId,
+        dir: Dir,
+    },
+    BotStabbed {
+        id: BotId,
+        target: Option<BotId>,
+    },
+    BotScanned {
+        id: BotId,
+        at: IVec2,
+        range: u8,
+    },
+    BotScored {
+        id: BotId,
+    },
+    BotRecharged {
+        id: BotId,
+        energy: u32,
+    },
+    BotTeleported {
+        id: BotId,
+        from: IVec2,
+        to: IVec2,
+    },
+    BotDiscarded {
+        id: BotId,
+    },
+
+    /// Bot has written something onto its serial port - the output itself
+    /// is available through the snapshot (see: [`crate::AliveBotSnapshot`]).
+    BotSerialWritten {
+        id: BotId,
+    },
+    ObjectPicked {
+        id: ObjectId,
+    },
+    ObjectDropped {
+        id: ObjectId,
+    },
+    ObjectGiven {
+        id: ObjectId,
+        from: BotId,
+        to: BotId,
+    },
+    MineExploded {
+        id: ObjectId,
+        at: IVec2,
+        victim: BotId,
+    },
+    DoorOpened {
+        at: IVec2,
+        by: Option<BotId>,
+    },
+    DoorClosed {
+        at: IVec2,
+        by: Option<BotId>,
+    },
+    TileChanged {
+        at: IVec2,
+    },
+}
+
+impl Event {
+    /// Returns key for events that can be emitted often enough to flood the
+    /// stream - out of events sharing the same key, only the latest one gets
+    /// sent, replacing the earlier ones (at their original position).
+    fn coalesce_key(&self) -> Option<(Discriminant<Self>, BotId)> {
+        match self {
+            Event::BotScanned { id, .. }
+            | Event::BotSerialWritten { id }
+            | Event::BotTurned { id, .. } => {
+                Some((mem::discriminant(self), *id))
+            }
+
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -39,3 +150,57 @@ pub struct EventLetter {
     pub event: Event,
     pub version: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coalesce() {
+        let (tx, mut rx) = broadcast::channel(16);
+
+        let mut target = Events {
+            tx,
+            pending: Default::default(),
+            coalesced: Default::default(),
+        };
+
+        target.push(Event::BotTurned {
+            id: BotId::new(1),
+            dir: Dir::N,
+        });
+        target.push(Event::BotBorn {
+            id: BotId::new(2),
+            team: None,
+        });
+        target.push(Event::BotTurned {
+            id: BotId::new(2),
+            dir: Dir::E,
+        });
+        target.push(Event::BotTurned {
+            id: BotId::new(1),
+            dir: Dir::S,
+        });
+        target.send(1);
+
+        let events: Vec<_> =
+            std::iter::from_fn(|| rx.try_recv().ok().map(|e| e.event))
+                .collect();
+
+        assert!(matches!(
+            events[..],
+            [
+                Event::BotTurned { dir: Dir::S, .. },
+                Event::BotBorn { .. },
+                Event::BotTurned { dir: Dir::E, .. },
+            ]
+        ));
+
+        target.push(Event::BotTurned {
+            id: BotId::new(1),
+            dir: Dir::W,
+        });
+
+        assert_eq!(1, target.pending.len());
+    }
+}
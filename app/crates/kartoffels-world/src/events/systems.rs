@@ -11,6 +11,6 @@ pub fn track(
     };
 
     for event in new_events.read() {
-        events.pending.push(*event);
+        events.push(*event);
     }
 }
@@ -0,0 +1,103 @@
+mod systems;
+
+pub use self::systems::*;
+use crate::Owner;
+use ahash::AHashMap;
+use bevy_ecs::system::Resource;
+use glam::{ivec2, IVec2, UVec2};
+use std::sync::Arc;
+
+/// Tiles explored by bots of each owner, used for fog of war (see:
+/// [`crate::Policy::fog`]).
+///
+/// Owners are sessions, which don't survive restarts, so this doesn't get
+/// persisted.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct Fog {
+    pub entries: AHashMap<Owner, Arc<Explored>>,
+}
+
+impl Fog {
+    pub fn get(&self, owner: Owner) -> Option<&Explored> {
+        self.entries.get(&owner).map(|explored| &**explored)
+    }
+}
+
+pub type FogSnapshot = Fog;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Explored {
+    size: UVec2,
+    bits: Box<[u64]>,
+}
+
+impl Explored {
+    pub fn new(size: UVec2) -> Self {
+        let len = (size.x * size.y).div_ceil(64) as usize;
+
+        Self {
+            size,
+            bits: vec![0; len].into_boxed_slice(),
+        }
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    pub fn get(&self, pos: IVec2) -> bool {
+        self.pos_to_idx(pos)
+            .is_some_and(|idx| self.bits[idx / 64] & (1 << (idx % 64)) != 0)
+    }
+
+    pub fn set(&mut self, pos: IVec2) {
+        if let Some(idx) = self.pos_to_idx(pos) {
+            self.bits[idx / 64] |= 1 << (idx % 64);
+        }
+    }
+
+    /// Marks area seen by radar of given range as explored.
+    pub fn reveal(&mut self, center: IVec2, range: u8) {
+        let r = (range / 2) as i32;
+
+        for dy in -r..=r {
+            for dx in -r..=r {
+                self.set(center + ivec2(dx, dy));
+            }
+        }
+    }
+
+    fn pos_to_idx(&self, pos: IVec2) -> Option<usize> {
+        let size = self.size.as_ivec2();
+
+        if pos.x >= 0 && pos.x < size.x && pos.y >= 0 && pos.y < size.y {
+            Some((pos.x + pos.y * size.x) as usize)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::uvec2;
+
+    #[test]
+    fn reveal() {
+        let mut explored = Explored::new(uvec2(10, 8));
+
+        explored.reveal(ivec2(0, 7), 5);
+
+        for y in 0..8 {
+            for x in 0..10 {
+                let expected = x <= 2 && y >= 5;
+
+                assert_eq!(expected, explored.get(ivec2(x, y)), "{x},{y}");
+            }
+        }
+
+        assert!(!explored.get(ivec2(-1, 7)));
+        assert!(!explored.get(ivec2(0, 8)));
+    }
+}
@@ -0,0 +1,3 @@
+mod explore;
+
+pub use self::explore::*;
@@ -0,0 +1,34 @@
+use crate::{Bots, Event, Explored, Fog, Map, Policy};
+use bevy_ecs::event::EventReader;
+use bevy_ecs::system::{Res, ResMut};
+use std::sync::Arc;
+
+/// Marks tiles scanned by bots' radars as explored by bots' owners.
+pub fn explore(
+    bots: Res<Bots>,
+    map: Res<Map>,
+    policy: Res<Policy>,
+    mut fog: ResMut<Fog>,
+    mut events: EventReader<Event>,
+) {
+    if !policy.fog {
+        return;
+    }
+
+    for event in events.read() {
+        let Event::BotScanned { id, at, range } = *event else {
+            continue;
+        };
+
+        let Some(owner) = bots.alive.get(id).and_then(|bot| bot.owner) else {
+            continue;
+        };
+
+        let explored = Arc::make_mut(
+            fog.entries
+                .entry(owner)
+                .or_insert_with(|| Arc::new(Explored::new(map.size()))),
+        );
+        explored.reveal(at, range);
+    }
+}
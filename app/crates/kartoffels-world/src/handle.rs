@@ -2,8 +2,8 @@ mod systems;
 
 pub use self::systems::*;
 use crate::{
-    BotId, Clock, Dir, EventLetter, EventStream, Map, Object, ObjectId,
-    Snapshot, SnapshotStream,
+    BotId, BotStanding, Clock, Dir, EventLetter, EventStream, Map, Object,
+    ObjectId, Owner, Snapshot, SnapshotStream, Team, Tile,
 };
 use anyhow::{anyhow, Context, Result};
 use arc_swap::{ArcSwap, Guard};
@@ -150,6 +150,49 @@ impl Handle {
         rx.await.context(Self::ERR)
     }
 
+    /// Replaces a single tile, e.g. to open or close a door - unlike
+    /// [`Self::set_map()`], this gets announced via
+    /// [`crate::Event::TileChanged`].
+    ///
+    /// Note that this doesn't check what's standing on the tile, so it's
+    /// possible to e.g. trap a bot inside a wall.
+    pub async fn set_tile(
+        &self,
+        pos: IVec2,
+        tile: impl Into<Tile>,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(Request::SetTile {
+            pos,
+            tile: tile.into(),
+            tx,
+        })
+        .await?;
+
+        rx.await.context(Self::ERR)?
+    }
+
+    /// Rebuilds the map from the world's theme, using a fresh seed - alive bots
+    /// get moved to random places on the new map.
+    pub async fn regenerate_map(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(Request::RegenerateMap { tx }).await?;
+
+        rx.await.context(Self::ERR)?
+    }
+
+    /// Removes all bots (alive, dead and queued) together with their history,
+    /// returning their final standings, best first.
+    pub async fn clear_bots(&self) -> Result<Vec<BotStanding>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(Request::ClearBots { tx }).await?;
+
+        rx.await.context(Self::ERR)
+    }
+
     pub async fn set_spawn(
         &self,
         pos: impl Into<Option<IVec2>>,
@@ -294,6 +337,24 @@ pub enum Request {
         tx: oneshot::Sender<()>,
     },
 
+    SetTile {
+        pos: IVec2,
+        tile: Tile,
+
+        #[derivative(Debug = "ignore")]
+        tx: oneshot::Sender<Result<()>>,
+    },
+
+    RegenerateMap {
+        #[derivative(Debug = "ignore")]
+        tx: oneshot::Sender<Result<()>>,
+    },
+
+    ClearBots {
+        #[derivative(Debug = "ignore")]
+        tx: oneshot::Sender<Vec<BotStanding>>,
+    },
+
     SetSpawn {
         pos: Option<IVec2>,
         dir: Option<Dir>,
@@ -334,6 +395,8 @@ pub struct CreateBotRequest {
     pub dir: Option<Dir>,
     pub instant: bool,
     pub oneshot: bool,
+    pub owner: Option<Owner>,
+    pub team: Option<Team>,
 }
 
 impl CreateBotRequest {
@@ -344,6 +407,8 @@ impl CreateBotRequest {
             dir: None,
             instant: false,
             oneshot: false,
+            owner: None,
+            team: None,
         }
     }
 
@@ -366,4 +431,20 @@ impl CreateBotRequest {
         self.oneshot = true;
         self
     }
+
+    /// Marks bot as belonging to given player - in worlds with fog of war,
+    /// this determines who gets to see what the bot explores (see:
+    /// [`crate::Policy::fog`]).
+    pub fn owner(mut self, owner: impl Into<Option<Owner>>) -> Self {
+        self.owner = owner.into();
+        self
+    }
+
+    /// Assigns bot to given team - if not specified, the world assigns the
+    /// team on its own, as long as it's got teams enabled (see:
+    /// [`crate::Policy::teams`]).
+    pub fn team(mut self, team: impl Into<Option<Team>>) -> Self {
+        self.team = team.into();
+        self
+    }
 }
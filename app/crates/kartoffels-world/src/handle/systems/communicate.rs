@@ -1,7 +1,9 @@
 use crate::{
-    Bots, Clock, CreateBot, Fuel, HandleRx, KillBot, Map, Objects, Paused,
-    Request, Shutdown, Spawn, WorldName, WorldRng,
+    Bots, ClearBots, Clock, CreateBot, DeathReason, Event, Fog, Fuel, HandleRx,
+    KillBot, Map, Objects, Paused, RegenerateMap, Request, Shutdown, Spawn,
+    Terrain, WorldName, WorldRng,
 };
+use anyhow::anyhow;
 use bevy_ecs::system::{Commands, Res, ResMut};
 use std::sync::Arc;
 use tokio::sync::mpsc::error::TryRecvError;
@@ -12,6 +14,7 @@ pub fn communicate(
     mut bots: ResMut<Bots>,
     mut clock: ResMut<Clock>,
     mut cmds: Commands,
+    mut fog: ResMut<Fog>,
     mut fuel: ResMut<Fuel>,
     mut map: ResMut<Map>,
     mut objects: ResMut<Objects>,
@@ -19,6 +22,7 @@ pub fn communicate(
     mut rng: ResMut<WorldRng>,
     mut rx: ResMut<HandleRx>,
     mut spawn: ResMut<Spawn>,
+    mut terrain: ResMut<Terrain>,
     name: Res<WorldName>,
 ) {
     fuel.tick(&clock);
@@ -81,8 +85,9 @@ pub fn communicate(
                 if let Some(bot) = bots.alive.remove(id) {
                     cmds.send_event(KillBot {
                         killed: Some(bot),
-                        reason,
+                        reason: DeathReason::KilledByAdmin,
                         killer: None,
+                        msg: reason,
                     });
                 }
 
@@ -97,10 +102,38 @@ pub fn communicate(
 
             Ok(Request::SetMap { map: new_map, tx }) => {
                 *map = new_map;
+                *fog = Default::default();
+                terrain.invalidate();
 
                 _ = tx.send(());
             }
 
+            Ok(Request::SetTile { pos, tile, tx }) => {
+                if !map.contains(pos) {
+                    _ = tx.send(Err(anyhow!(
+                        "{},{} is outside of the map",
+                        pos.x,
+                        pos.y
+                    )));
+
+                    continue;
+                }
+
+                if map.set(pos, tile) {
+                    cmds.send_event(Event::TileChanged { at: pos });
+                }
+
+                _ = tx.send(Ok(()));
+            }
+
+            Ok(Request::RegenerateMap { tx }) => {
+                cmds.send_event(RegenerateMap { tx: Some(tx) });
+            }
+
+            Ok(Request::ClearBots { tx }) => {
+                cmds.send_event(ClearBots { tx: Some(tx) });
+            }
+
             Ok(Request::SetSpawn { pos, dir, tx }) => {
                 spawn.pos = pos;
                 spawn.dir = dir;
@@ -109,7 +142,7 @@ pub fn communicate(
             }
 
             Ok(Request::CreateObject { obj, pos, tx }) => {
-                _ = tx.send(objects.create(&mut rng.0, obj, pos));
+                _ = tx.send(objects.create(&mut rng.0, &bots.alive, obj, pos));
             }
 
             Ok(Request::DeleteObject { id, tx }) => {
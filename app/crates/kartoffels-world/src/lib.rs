@@ -12,13 +12,17 @@ mod bots;
 mod clock;
 mod config;
 mod events;
+mod fog;
 mod handle;
 mod lifecycle;
 mod lives;
 mod map;
+mod markers;
+mod mode;
 mod object;
 mod objects;
 mod policy;
+mod reset;
 mod snapshots;
 mod spec;
 mod stats;
@@ -27,26 +31,40 @@ mod theme;
 mod utils;
 
 pub mod cfg {
-    pub const EVENT_STREAM_CAPACITY: usize = 128;
+    pub const EVENT_STREAM_CAPACITY: usize = 1024;
     pub const REQUEST_STREAM_CAPACITY: usize = 128;
     pub const MAX_LIVES_PER_BOT: usize = 128;
+
+    /// Minimum number of alive bots per thread when ticking bots in parallel;
+    /// handing work over to other threads isn't free, so smaller worlds are
+    /// always ticked on the world's own thread.
+    pub const BOTS_PER_THREAD: usize = 64;
 }
 
 pub mod prelude {
-    pub use crate::bot::BotId;
+    pub use crate::bot::{
+        BotDisplay, BotId, DeathReason, FirmwareFault, Owner, Team,
+    };
     pub use crate::clock::Clock;
     pub use crate::config::Config;
     pub use crate::events::{Event, EventLetter, EventStream};
+    pub use crate::fog::{Explored, Fog, FogSnapshot};
     pub use crate::handle::{CreateBotRequest, Handle, Request};
     pub use crate::map::{Map, MapBuilder, Tile, TileKind};
+    pub use crate::mode::{GameMode, GameZone};
     pub use crate::object::{Object, ObjectId, ObjectKind};
-    pub use crate::policy::Policy;
+    pub use crate::policy::{
+        Policy, SpawnArea, SpawnPolicy, SpawnStrategy, SpawnZone,
+    };
+    pub use crate::reset::BotStanding;
     pub use crate::snapshots::{
         AliveBotSnapshot, AliveBotsSnapshot, BotSnapshot, BotsSnapshot,
         DeadBotSnapshot, DeadBotsSnapshot, ObjectsSnapshot, QueuedBotSnapshot,
         QueuedBotsSnapshot, Snapshot, SnapshotStream,
     };
-    pub use crate::theme::{ArenaTheme, CaveTheme, Theme};
+    pub use crate::theme::{
+        ArenaTheme, CaveTheme, CustomTheme, DungeonTheme, MazeTheme, Theme,
+    };
     pub use crate::utils::Dir;
 }
 
@@ -55,13 +73,17 @@ pub(crate) use self::bots::*;
 pub(crate) use self::clock::*;
 pub(crate) use self::config::*;
 pub(crate) use self::events::*;
+pub(crate) use self::fog::*;
 pub(crate) use self::handle::*;
 pub(crate) use self::lifecycle::*;
 pub(crate) use self::lives::*;
 pub(crate) use self::map::*;
+pub(crate) use self::markers::*;
+pub(crate) use self::mode::*;
 pub(crate) use self::object::*;
 pub(crate) use self::objects::*;
 pub(crate) use self::policy::*;
+pub(crate) use self::reset::*;
 pub(crate) use self::snapshots::*;
 pub(crate) use self::stats::*;
 pub(crate) use self::storage::*;
@@ -70,7 +92,10 @@ pub(crate) use self::utils::*;
 use anyhow::Result;
 use arc_swap::ArcSwap;
 use bevy_ecs::event::EventRegistry;
-use bevy_ecs::schedule::{ExecutorKind, IntoSystemConfigs, Schedule};
+use bevy_ecs::schedule::common_conditions::on_event;
+use bevy_ecs::schedule::{
+    Condition, ExecutorKind, IntoSystemConfigs, Schedule,
+};
 use bevy_ecs::system::Res;
 use bevy_ecs::world::World;
 use futures_util::FutureExt;
@@ -106,17 +131,34 @@ pub fn create(config: Config) -> Handle {
         })
         .unwrap_or_default();
 
+    let mut objects = Objects::default();
+    let mut policy = config.policy;
+
+    if let Some(theme) = &config.theme {
+        for (obj, pos) in theme.objects() {
+            objects.create(&mut rng, &AliveBots::default(), obj, Some(pos));
+        }
+
+        policy.spawn.zones.extend(theme.spawn_zones());
+    }
+
     let res = Resources {
         bots: Default::default(),
         clock: config.clock,
+        cpu_rng: Default::default(),
+        flag_homes: Default::default(),
         id: WorldId(id),
         lives: Default::default(),
         map,
+        markers: Default::default(),
         name: WorldName(Arc::new(ArcSwap::from_pointee(config.name))),
+        objects,
         path: config.path.map(WorldPath),
-        policy: config.policy,
+        policy,
         rng: WorldRng(rng),
+        terrain: Default::default(),
         theme: config.theme,
+        threads: WorldThreads::new(config.threads),
     };
 
     create_or_resume(res, config.events)
@@ -125,18 +167,28 @@ pub fn create(config: Config) -> Handle {
 pub fn resume(id: Id, path: &Path) -> Result<Handle> {
     let world = storage::load(path)?;
     let name = Arc::new(ArcSwap::from_pointee(world.name.into_owned()));
+    let bots = world.bots.into_owned();
+    let mut objects = world.objects.into_owned();
+
+    objects.remove_carried(&bots.alive);
 
     let res = Resources {
-        bots: world.bots.into_owned(),
+        bots,
         clock: Default::default(),
+        cpu_rng: world.cpu_rng.into_owned(),
+        flag_homes: world.flag_homes.into_owned(),
         id: WorldId(id),
         lives: world.lives.into_owned(),
         map: world.map.into_owned(),
+        markers: world.markers.into_owned(),
         name: WorldName(name),
+        objects,
         path: Some(WorldPath(path.to_owned())),
         policy: world.policy.into_owned(),
-        rng: WorldRng(ChaCha8Rng::from_entropy()),
+        rng: WorldRng(world.rng.into_owned()),
+        terrain: world.terrain.into_owned(),
         theme: world.theme.map(|theme| theme.into_owned()),
+        threads: WorldThreads::new(None),
     };
 
     Ok(create_or_resume(res, false))
@@ -145,14 +197,20 @@ pub fn resume(id: Id, path: &Path) -> Result<Handle> {
 struct Resources {
     bots: Bots,
     clock: Clock,
+    cpu_rng: CpuRng,
+    flag_homes: FlagHomes,
     id: WorldId,
     lives: Lives,
     map: Map,
+    markers: Markers,
     name: WorldName,
+    objects: Objects,
     path: Option<WorldPath>,
     policy: Policy,
     rng: WorldRng,
+    terrain: Terrain,
     theme: Option<Theme>,
+    threads: WorldThreads,
 }
 
 fn create_or_resume(res: Resources, emit_events: bool) -> Handle {
@@ -170,11 +228,17 @@ fn create_world(res: Resources) -> World {
     world.insert_resource(res.bots);
     world.insert_resource(res.clock.metronome());
     world.insert_resource(res.clock);
+    world.insert_resource(res.cpu_rng);
+    world.insert_resource(res.flag_homes);
     world.insert_resource(res.id);
     world.insert_resource(res.map);
+    world.insert_resource(res.markers);
     world.insert_resource(res.name);
+    world.insert_resource(res.objects);
     world.insert_resource(res.policy);
     world.insert_resource(res.rng);
+    world.insert_resource(res.terrain);
+    world.insert_resource(res.threads);
     world.insert_resource(res.lives);
 
     if let Some(path) = res.path {
@@ -185,17 +249,20 @@ fn create_world(res: Resources) -> World {
         world.insert_resource(theme);
     }
 
+    world.insert_resource(Fog::default());
     world.insert_resource(Fuel::default());
-    world.insert_resource(Objects::default()); // TODO persist
     world.insert_resource(Paused::default());
+    world.insert_resource(PendingMap::default());
     world.insert_resource(Spawn::default());
     world.insert_resource(Stats::default());
 
     // ---
 
+    EventRegistry::register_event::<ClearBots>(&mut world);
     EventRegistry::register_event::<CreateBot>(&mut world);
     EventRegistry::register_event::<Event>(&mut world);
     EventRegistry::register_event::<KillBot>(&mut world);
+    EventRegistry::register_event::<RegenerateMap>(&mut world);
     EventRegistry::register_event::<SpawnBot>(&mut world);
 
     // ---
@@ -223,6 +290,7 @@ fn create_handle(world: &mut World, emit_events: bool) -> Handle {
         world.insert_resource(Events {
             tx: events.clone(),
             pending: Default::default(),
+            coalesced: Default::default(),
         });
     }
 
@@ -267,15 +335,37 @@ fn main_schedule() -> Schedule {
         !paused.get()
     }
 
+    fn fogged(policy: Res<Policy>) -> bool {
+        policy.fog
+    }
+
+    fn playing(
+        mode: fn(&GameMode) -> bool,
+    ) -> impl FnMut(Res<Policy>) -> bool + Clone {
+        move |policy| mode(&policy.mode)
+    }
+
     schedule((
         handle::communicate,
+        reset::clear_bots.run_if(on_event::<ClearBots>),
+        reset::regenerate_map.run_if(reset::is_map_requested),
         bots::create,
         bots::schedule_spawn.run_if(active),
         bots::spawn,
-        bots::tick.run_if(active),
+        (bots::tick, bots::terrain).chain().run_if(active),
+        markers::decay.run_if(active),
+        mode::score_kills
+            .run_if(playing(|mode| matches!(mode, GameMode::Deathmatch))),
         bots::kill,
-        lives::update,
-        stats::update,
+        mode::score_gems
+            .run_if(playing(|mode| matches!(mode, GameMode::GemCollection))),
+        mode::score_flags.run_if(playing(|mode| {
+            matches!(mode, GameMode::CaptureTheFlag { .. })
+        })),
+        mode::score_hill.run_if(active.and(playing(|mode| {
+            matches!(mode, GameMode::KingOfTheHill { .. })
+        }))),
+        (lives::update, stats::update, fog::explore.run_if(fogged)).chain(),
         events::track,
         snapshots::send,
         storage::save,
@@ -1,4 +1,4 @@
-use crate::{cfg, BotId, Clock, Event, Ticks};
+use crate::{cfg, BotId, Clock, DeathReason, Event, Team, Ticks};
 use ahash::AHashMap;
 use bevy_ecs::event::EventReader;
 use bevy_ecs::system::{Res, ResMut, Resource};
@@ -29,9 +29,12 @@ pub fn update(
 ) {
     for event in events.read() {
         match *event {
-            Event::BotBorn { id } => match lives.entries.entry(id) {
+            Event::BotBorn { id, team } => match lives.entries.entry(id) {
                 hash_map::Entry::Occupied(entry) => {
-                    Arc::make_mut(entry.into_mut()).curr.born_at = clock.now();
+                    let curr = &mut Arc::make_mut(entry.into_mut()).curr;
+
+                    curr.born_at = clock.now();
+                    curr.team = team;
                 }
 
                 hash_map::Entry::Vacant(entry) => {
@@ -39,6 +42,7 @@ pub fn update(
                         curr: CurrBotLife {
                             score: 0,
                             born_at: clock.now(),
+                            team,
                         },
                         prev: Default::default(),
                         len: 0,
@@ -55,13 +59,18 @@ pub fn update(
                     .on_bot_scored();
             }
 
-            Event::BotDied { id, age } => {
+            Event::BotDied {
+                id,
+                age,
+                reason,
+                killer,
+            } => {
                 lives
                     .entries
                     .get_mut(&id)
                     .map(Arc::make_mut)
                     .unwrap()
-                    .on_bot_died(&clock, age);
+                    .on_bot_died(&clock, age, reason, killer);
             }
 
             Event::BotDiscarded { id } => {
@@ -85,7 +94,13 @@ impl BotLives {
         self.curr.score = self.curr.score.saturating_add(1);
     }
 
-    fn on_bot_died(&mut self, clock: &Clock, age: Ticks) {
+    fn on_bot_died(
+        &mut self,
+        clock: &Clock,
+        age: Ticks,
+        reason: DeathReason,
+        killer: Option<BotId>,
+    ) {
         if self.prev.len() >= cfg::MAX_LIVES_PER_BOT {
             self.prev.pop_front();
         }
@@ -95,6 +110,9 @@ impl BotLives {
             score: self.curr.score,
             born_at: self.curr.born_at,
             died_at: clock.now(),
+            reason: Some(reason),
+            killer,
+            team: self.curr.team,
         });
 
         self.curr = Default::default();
@@ -107,6 +125,9 @@ impl BotLives {
             score: self.curr.score,
             born_at: self.curr.born_at,
             died_at: None,
+            reason: None,
+            killer: None,
+            team: self.curr.team,
         });
 
         let prev = self.prev.iter().rev().map(|life| BotLife {
@@ -114,6 +135,9 @@ impl BotLives {
             score: life.score,
             born_at: life.born_at,
             died_at: Some(life.died_at),
+            reason: life.reason,
+            killer: life.killer,
+            team: life.team,
         });
 
         curr.into_iter().chain(prev)
@@ -130,6 +154,9 @@ pub struct BotLife {
     pub score: u32,
     pub born_at: DateTime<Utc>,
     pub died_at: Option<DateTime<Utc>>,
+    pub reason: Option<DeathReason>,
+    pub killer: Option<BotId>,
+    pub team: Option<Team>,
 }
 
 #[derive(
@@ -138,6 +165,8 @@ pub struct BotLife {
 pub struct CurrBotLife {
     pub score: u32,
     pub born_at: DateTime<Utc>,
+
+    pub team: Option<Team>,
 }
 
 impl CurrBotLife {
@@ -152,4 +181,10 @@ pub struct PrevBotLife {
     pub score: u32,
     pub born_at: DateTime<Utc>,
     pub died_at: DateTime<Utc>,
+
+    /// Why the bot died; `None` for lives recorded before we started tracking
+    /// this.
+    pub reason: Option<DeathReason>,
+    pub killer: Option<BotId>,
+    pub team: Option<Team>,
 }
@@ -1,6 +1,7 @@
 mod builder;
 
 pub use self::builder::*;
+use crate::Dir;
 use ahash::HashMap;
 use bevy_ecs::system::Resource;
 use glam::{ivec2, uvec2, IVec2, UVec2};
@@ -293,6 +294,71 @@ impl Tile {
     pub fn is_bot(&self) -> bool {
         self.kind == TileKind::BOT
     }
+
+    /// Whether bots can drive onto this tile - note that some of those tiles
+    /// are not particularly friendly, e.g. [`TileKind::LAVA`].
+    pub fn is_walkable(&self) -> bool {
+        matches!(
+            self.kind,
+            TileKind::CONVEYOR
+                | TileKind::DOOR_OPEN
+                | TileKind::FLOOR
+                | TileKind::LAVA
+                | TileKind::PLATE
+                | TileKind::WATER
+        )
+    }
+
+    pub fn is_door(&self) -> bool {
+        self.kind == TileKind::DOOR || self.kind == TileKind::DOOR_OPEN
+    }
+
+    pub fn door(channel: u8) -> Self {
+        Self {
+            kind: TileKind::DOOR,
+            meta: [channel, 0, 0],
+        }
+    }
+
+    pub fn plate(channel: u8) -> Self {
+        Self {
+            kind: TileKind::PLATE,
+            meta: [channel, 0, 0],
+        }
+    }
+
+    pub fn switch(channel: u8) -> Self {
+        Self {
+            kind: TileKind::SWITCH,
+            meta: [channel, 0, 0],
+        }
+    }
+
+    /// For doors, pressure plates and switches, returns the channel - plates
+    /// and switches control doors on the same channel.
+    ///
+    /// Channel zero means "not wired" - such doors can be opened only with a
+    /// key, while such plates and switches don't control anything.
+    pub fn channel(&self) -> u8 {
+        self.meta[0]
+    }
+
+    /// For switches, returns whether the switch is flipped on.
+    pub fn is_on(&self) -> bool {
+        self.kind == TileKind::SWITCH && self.meta[1] == 1
+    }
+
+    pub fn conveyor(dir: Dir) -> Self {
+        Self {
+            kind: TileKind::CONVEYOR,
+            meta: [dir.into(), 0, 0],
+        }
+    }
+
+    /// For conveyors, returns the direction bots get pushed towards.
+    pub fn conveyor_dir(&self) -> Option<Dir> {
+        (self.kind == TileKind::CONVEYOR).then(|| Dir::from(self.meta[0]))
+    }
 }
 
 impl From<u8> for Tile {
@@ -335,10 +401,34 @@ pub struct TileKind;
 impl TileKind {
     pub const BOT: u8 = b'@';
     pub const BOT_CHEVRON: u8 = b'~';
+
+    /// Pushes bots standing on it one tile further, towards the direction
+    /// stored in tile's metadata (see: [`Tile::conveyor()`]).
+    pub const CONVEYOR: u8 = b'>';
+
+    /// Door that can be opened with a key, a switch or a pressure plate (see:
+    /// [`Tile::door()`]).
     pub const DOOR: u8 = b'+';
+
+    pub const DOOR_OPEN: u8 = b'\'';
     pub const FLOOR: u8 = b'.';
+
+    /// Kills bots that stay on it for too long.
+    pub const LAVA: u8 = b'"';
+
+    /// Keeps doors on its channel open for as long as there's a bot standing
+    /// on it (see: [`Tile::plate()`]).
+    pub const PLATE: u8 = b'_';
+
+    /// Opens or closes doors on its channel when used with bot's arm (see:
+    /// [`Tile::switch()`]).
+    pub const SWITCH: u8 = b'\\';
+
     pub const VOID: u8 = b' ';
     pub const WALL: u8 = b'#';
     pub const WALL_H: u8 = b'-';
     pub const WALL_V: u8 = b'|';
+
+    /// Slows down bots driving through it.
+    pub const WATER: u8 = b':';
 }
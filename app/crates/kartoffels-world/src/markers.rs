@@ -0,0 +1,159 @@
+use crate::Clock;
+use ahash::AHashMap;
+use bevy_ecs::system::{Res, ResMut, Resource};
+use glam::IVec2;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Per-tile marker layer that bots can write (see: [`crate::BotAction`]) and
+/// sense through the radar - think pheromones.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct Markers {
+    entries: AHashMap<IVec2, Marker>,
+}
+
+impl Markers {
+    /// How many ticks it takes for a decaying marker to lose one point of its
+    /// value.
+    pub const DECAY_TICKS: u32 = 16_000;
+
+    pub fn get(&self, pos: IVec2) -> u8 {
+        self.entries
+            .get(&pos)
+            .map(|marker| marker.value)
+            .unwrap_or(0)
+    }
+
+    pub fn set(&mut self, pos: IVec2, value: u8, decays: bool) {
+        if value == 0 {
+            self.entries.remove(&pos);
+        } else {
+            self.entries.insert(
+                pos,
+                Marker {
+                    value,
+                    decays_in: decays.then_some(Self::DECAY_TICKS),
+                },
+            );
+        }
+    }
+
+    fn decay(&mut self, ticks: u32) {
+        self.entries.retain(|_, marker| {
+            let Some(decays_in) = &mut marker.decays_in else {
+                return true;
+            };
+
+            let mut ticks = ticks;
+
+            while ticks >= *decays_in {
+                ticks -= *decays_in;
+                *decays_in = Self::DECAY_TICKS;
+                marker.value -= 1;
+
+                if marker.value == 0 {
+                    return false;
+                }
+            }
+
+            *decays_in -= ticks;
+
+            true
+        });
+    }
+}
+
+impl Serialize for Markers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.entries.iter().map(|(pos, marker)| {
+            MarkerEntry {
+                pos: *pos,
+                value: marker.value,
+                decays_in: marker.decays_in,
+            }
+        }))
+    }
+}
+
+impl<'de> Deserialize<'de> for Markers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = Vec::<MarkerEntry>::deserialize(deserializer)?
+            .into_iter()
+            .filter(|entry| entry.value > 0)
+            .map(|entry| {
+                let marker = Marker {
+                    value: entry.value,
+                    decays_in: entry.decays_in,
+                };
+
+                (entry.pos, marker)
+            })
+            .collect();
+
+        Ok(Self { entries })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Marker {
+    value: u8,
+    decays_in: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct MarkerEntry {
+    pos: IVec2,
+    value: u8,
+    decays_in: Option<u32>,
+}
+
+pub fn decay(clock: Res<Clock>, mut markers: ResMut<Markers>) {
+    markers.decay(clock.ticks());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::ivec2;
+
+    #[test]
+    fn smoke() {
+        let mut target = Markers::default();
+
+        target.set(ivec2(1, 2), 2, true);
+        target.set(ivec2(3, 4), 2, false);
+
+        assert_eq!(2, target.get(ivec2(1, 2)));
+        assert_eq!(2, target.get(ivec2(3, 4)));
+        assert_eq!(0, target.get(ivec2(5, 6)));
+
+        // ---
+
+        target.decay(Markers::DECAY_TICKS - 1);
+
+        assert_eq!(2, target.get(ivec2(1, 2)));
+        assert_eq!(2, target.get(ivec2(3, 4)));
+
+        target.decay(1);
+
+        assert_eq!(1, target.get(ivec2(1, 2)));
+        assert_eq!(2, target.get(ivec2(3, 4)));
+
+        target.decay(10 * Markers::DECAY_TICKS);
+
+        assert_eq!(0, target.get(ivec2(1, 2)));
+        assert_eq!(2, target.get(ivec2(3, 4)));
+        assert_eq!(1, target.entries.len());
+
+        // ---
+
+        target.set(ivec2(3, 4), 0, false);
+
+        assert!(target.entries.is_empty());
+    }
+}
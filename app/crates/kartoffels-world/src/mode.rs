@@ -0,0 +1,212 @@
+mod systems;
+
+pub use self::systems::*;
+use crate::{AliveBots, Map, Object, ObjectId, Objects, Team};
+use anyhow::{anyhow, Context, Error, Result};
+use bevy_ecs::system::Resource;
+use glam::{ivec2, IVec2, UVec2};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Determines how bots score points - and so what the leaderboard means.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum GameMode {
+    /// Bots score by killing other bots - except for their teammates.
+    #[default]
+    #[serde(rename = "deathmatch")]
+    Deathmatch,
+
+    /// Bots score by picking gems; picked gem doesn't go into the inventory,
+    /// it rather reappears somewhere else on the map.
+    #[serde(rename = "gem-collection")]
+    GemCollection,
+
+    /// Bots score by bringing flags into their bases; captured flag goes back
+    /// to where it was lying originally.
+    #[serde(rename = "capture-the-flag")]
+    CaptureTheFlag { bases: Vec<GameZone> },
+
+    /// Bots score a point for each second spent on the hill, as long as the
+    /// hill isn't contested - i.e. as long as there's no one else from other
+    /// team on the hill (or, for bots without teams, no one else at all).
+    #[serde(rename = "king-of-the-hill")]
+    KingOfTheHill { hill: GameZone },
+}
+
+impl FromStr for GameMode {
+    type Err = Error;
+
+    /// Parses mode's name - bases and the hill have to be provided separately,
+    /// see [`crate::Policy::from_str()`].
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "deathmatch" => Ok(Self::Deathmatch),
+            "gem-collection" => Ok(Self::GemCollection),
+            "capture-the-flag" => Ok(Self::CaptureTheFlag {
+                bases: Default::default(),
+            }),
+            "king-of-the-hill" => Ok(Self::KingOfTheHill {
+                hill: Default::default(),
+            }),
+            _ => Err(anyhow!("unknown mode: {s}")),
+        }
+    }
+}
+
+/// Rectangular area used by some of the modes - capture-the-flag's bases and
+/// king-of-the-hill's hill.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct GameZone {
+    pub min: IVec2,
+    pub max: IVec2,
+
+    /// Team this zone belongs to - if set, only this team's bots can capture
+    /// flags in it; ignored for the hill.
+    pub team: Option<Team>,
+}
+
+impl GameZone {
+    pub fn new(min: IVec2, max: IVec2) -> Self {
+        Self {
+            min,
+            max,
+            team: None,
+        }
+    }
+
+    pub fn with_team(mut self, team: impl Into<Option<Team>>) -> Self {
+        self.team = team.into();
+        self
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(self.min).all() && pos.cmple(self.max).all()
+    }
+
+    pub fn accepts(&self, team: Option<Team>) -> bool {
+        self.team.is_none() || self.team == team
+    }
+}
+
+impl FromStr for GameZone {
+    type Err = Error;
+
+    /// Parses zone from `x1:y1:x2:y2` or `x1:y1:x2:y2:team`, where both
+    /// corners are inclusive.
+    fn from_str(spec: &str) -> Result<Self> {
+        let nums = spec
+            .split(':')
+            .map(|num| num.parse())
+            .collect::<Result<Vec<i32>, _>>()
+            .context("couldn't parse coordinates")?;
+
+        let (x1, y1, x2, y2, team) = match nums[..] {
+            [x1, y1, x2, y2] => (x1, y1, x2, y2, None),
+
+            [x1, y1, x2, y2, team] => {
+                let team = u8::try_from(team).context("invalid team")?;
+
+                (x1, y1, x2, y2, Some(Team::new(team)))
+            }
+
+            _ => {
+                return Err(anyhow!(
+                    "expected `x1:y1:x2:y2` or `x1:y1:x2:y2:team`"
+                ));
+            }
+        };
+
+        let min = ivec2(x1, y1).min(ivec2(x2, y2));
+        let max = ivec2(x1, y1).max(ivec2(x2, y2));
+
+        Ok(Self::new(min, max).with_team(team))
+    }
+}
+
+/// Looks for a random empty floor tile, e.g. to put a collected gem back.
+pub(crate) fn sample_free_pos(
+    rng: &mut impl RngCore,
+    map: &Map,
+    bots: &AliveBots,
+    objects: &Objects,
+) -> Option<IVec2> {
+    if map.size() == UVec2::ZERO {
+        return None;
+    }
+
+    (0..1024)
+        .map(|_| map.sample_pos(rng))
+        .find(|&pos| is_pos_free(map, bots, objects, pos))
+}
+
+/// Positions where flags were lying when they were seen for the first time,
+/// so that captured flags can be returned there - see
+/// [`GameMode::CaptureTheFlag`].
+///
+/// This is persisted, because a flag that's being carried around while the
+/// world gets restarted would otherwise forget its home.
+#[derive(Clone, Debug, Default, Resource, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FlagHomes(pub BTreeMap<ObjectId, IVec2>);
+
+impl FlagHomes {
+    /// Puts flag back onto the map - where it was lying originally or, if that
+    /// place is taken, somewhere random.
+    pub fn put_back(
+        &self,
+        rng: &mut impl RngCore,
+        map: &Map,
+        bots: &AliveBots,
+        objects: &mut Objects,
+        id: ObjectId,
+        obj: Object,
+    ) {
+        let pos = self
+            .0
+            .get(&id)
+            .copied()
+            .filter(|&pos| is_pos_free(map, bots, objects, pos))
+            .or_else(|| sample_free_pos(rng, map, bots, objects));
+
+        if let Some(pos) = pos {
+            objects.add(id, obj, Some(pos));
+        }
+    }
+}
+
+fn is_pos_free(
+    map: &Map,
+    bots: &AliveBots,
+    objects: &Objects,
+    pos: IVec2,
+) -> bool {
+    map.get(pos).is_floor()
+        && bots.lookup_at(pos).is_none()
+        && objects.lookup_at(pos).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zone_from_str() {
+        assert_eq!(
+            GameZone::new(ivec2(1, 2), ivec2(3, 4)),
+            GameZone::from_str("3:4:1:2").unwrap(),
+        );
+
+        assert_eq!(
+            GameZone::new(ivec2(1, 2), ivec2(3, 4)).with_team(Team::new(1)),
+            GameZone::from_str("1:2:3:4:1").unwrap(),
+        );
+
+        assert!(GameZone::from_str("1:2:3").is_err());
+        assert!(GameZone::from_str("1:2:3:4:256").is_err());
+    }
+}
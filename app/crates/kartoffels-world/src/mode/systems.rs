@@ -0,0 +1,9 @@
+mod score_flags;
+mod score_gems;
+mod score_hill;
+mod score_kills;
+
+pub use self::score_flags::*;
+pub use self::score_gems::*;
+pub use self::score_hill::*;
+pub use self::score_kills::*;
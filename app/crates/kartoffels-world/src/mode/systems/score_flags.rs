@@ -0,0 +1,62 @@
+use crate::{
+    Bots, Clock, Event, FlagHomes, GameMode, Map, ObjectKind, Objects, Policy,
+    WorldRng,
+};
+use bevy_ecs::system::{Commands, Res, ResMut};
+
+/// Awards bots for capturing flags in [`GameMode::CaptureTheFlag`].
+#[allow(clippy::too_many_arguments)]
+pub fn score_flags(
+    mut cmds: Commands,
+    clock: Res<Clock>,
+    policy: Res<Policy>,
+    map: Res<Map>,
+    mut bots: ResMut<Bots>,
+    mut objects: ResMut<Objects>,
+    mut rng: ResMut<WorldRng>,
+    mut homes: ResMut<FlagHomes>,
+) {
+    let GameMode::CaptureTheFlag { bases } = &policy.mode else {
+        return;
+    };
+
+    // Remember where each flag was lying when we saw it for the first time,
+    // so that we know where to return it after it gets captured
+    for obj in objects.iter() {
+        if obj.obj.kind == ObjectKind::FLAG
+            && let Some(pos) = obj.pos
+        {
+            homes.0.entry(obj.id).or_insert(pos);
+        }
+    }
+
+    // Forget flags that don't exist anymore, e.g. because they've been
+    // deleted by an admin
+    homes.0.retain(|&id, _| {
+        objects.get(id).is_some()
+            || bots.alive.iter().any(|bot| bot.inventory.contains(id))
+    });
+
+    let mut flags = Vec::new();
+
+    for bot in bots.alive.iter_mut() {
+        let is_at_base = bases
+            .iter()
+            .any(|base| base.contains(bot.pos) && base.accepts(bot.team));
+
+        if !is_at_base {
+            continue;
+        }
+
+        while let Some(flag) = bot.inventory.take_kind(ObjectKind::FLAG) {
+            bot.log(&clock, "captured flag");
+            flags.push(flag);
+
+            cmds.send_event(Event::BotScored { id: bot.id });
+        }
+    }
+
+    for (id, obj) in flags {
+        homes.put_back(&mut rng.0, &map, &bots.alive, &mut objects, id, obj);
+    }
+}
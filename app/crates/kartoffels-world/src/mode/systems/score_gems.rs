@@ -0,0 +1,40 @@
+use crate::mode::sample_free_pos;
+use crate::{
+    Bots, Clock, Event, GameMode, Map, ObjectKind, Objects, Policy, WorldRng,
+};
+use bevy_ecs::system::{Commands, Res, ResMut};
+
+/// Awards bots for picking gems in [`GameMode::GemCollection`].
+pub fn score_gems(
+    mut cmds: Commands,
+    clock: Res<Clock>,
+    policy: Res<Policy>,
+    map: Res<Map>,
+    mut bots: ResMut<Bots>,
+    mut objects: ResMut<Objects>,
+    mut rng: ResMut<WorldRng>,
+) {
+    if policy.mode != GameMode::GemCollection {
+        return;
+    }
+
+    let mut gems = Vec::new();
+
+    for bot in bots.alive.iter_mut() {
+        while let Some(gem) = bot.inventory.take_kind(ObjectKind::GEM) {
+            bot.log(&clock, "collected gem");
+            gems.push(gem);
+
+            cmds.send_event(Event::BotScored { id: bot.id });
+        }
+    }
+
+    // Put collected gems back on the map, so that the game can go on
+    for (id, obj) in gems {
+        if let Some(pos) =
+            sample_free_pos(&mut rng.0, &map, &bots.alive, &objects)
+        {
+            objects.add(id, obj, Some(pos));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Bots, Clock, Event, GameMode, Policy};
+use bevy_ecs::system::{Commands, Local, Res};
+
+/// Awards bots standing on the hill in [`GameMode::KingOfTheHill`].
+pub fn score_hill(
+    mut cmds: Commands,
+    clock: Res<Clock>,
+    policy: Res<Policy>,
+    bots: Res<Bots>,
+    mut ticks: Local<u32>,
+) {
+    let GameMode::KingOfTheHill { hill } = &policy.mode else {
+        return;
+    };
+
+    *ticks += clock.ticks();
+
+    if *ticks < Clock::HZ {
+        return;
+    }
+
+    *ticks -= Clock::HZ;
+
+    let kings: Vec<_> = bots
+        .alive
+        .iter()
+        .filter(|bot| hill.contains(bot.pos))
+        .collect();
+
+    let Some(king) = kings.first() else {
+        return;
+    };
+
+    let is_contested = if king.team.is_some() {
+        kings.iter().any(|bot| bot.team != king.team)
+    } else {
+        kings.len() > 1
+    };
+
+    if is_contested {
+        return;
+    }
+
+    for bot in kings {
+        cmds.send_event(Event::BotScored { id: bot.id });
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{Event, GameMode, KillBot, Policy};
+use bevy_ecs::event::EventReader;
+use bevy_ecs::system::{Commands, Res};
+
+/// Awards killers in [`GameMode::Deathmatch`].
+///
+/// Must run before `bots::kill()`, which takes the killed bots out of their
+/// events.
+pub fn score_kills(
+    mut cmds: Commands,
+    policy: Res<Policy>,
+    mut events: EventReader<KillBot>,
+) {
+    if policy.mode != GameMode::Deathmatch {
+        return;
+    }
+
+    for event in events.read() {
+        let (Some(killed), Some(killer)) = (&event.killed, event.killer())
+        else {
+            continue;
+        };
+
+        // Killing a teammate doesn't count as score - note that we're using
+        // killer's team from the moment of the attack, since by now the killer
+        // might be dead as well
+        if killed.team.is_some() && killed.team == killer.team {
+            continue;
+        }
+
+        cmds.send_event(Event::BotScored { id: killer.id });
+    }
+}
@@ -19,11 +19,33 @@ impl Object {
 
     pub fn name(&self) -> &'static str {
         match self.kind {
+            ObjectKind::BATTERY => "battery",
+            ObjectKind::BRICK => "brick",
             ObjectKind::FLAG => "flag",
             ObjectKind::GEM => "gem",
+            ObjectKind::KEY => "key",
+            ObjectKind::MINE => "mine",
+            ObjectKind::TELEPORTER => "teleporter",
             _ => "unknown object",
         }
     }
+
+    /// Whether bots can pick this object up - mines and teleporters are
+    /// fixtures of the map.
+    pub fn is_pickable(&self) -> bool {
+        !matches!(self.kind, ObjectKind::MINE | ObjectKind::TELEPORTER)
+    }
+
+    /// Whether bots can drive onto this object.
+    pub fn is_walkable(&self) -> bool {
+        self.kind == ObjectKind::TELEPORTER
+    }
+
+    /// For teleporters, returns the channel - bot stepping onto a teleporter
+    /// gets moved to another teleporter on the same channel.
+    pub fn channel(&self) -> u8 {
+        self.meta[0]
+    }
 }
 
 impl Serialize for Object {
@@ -58,8 +80,23 @@ impl<'de> Deserialize<'de> for Object {
 pub struct ObjectKind;
 
 impl ObjectKind {
+    /// Recharges bot's battery when picked up (the object itself disappears).
+    pub const BATTERY: u8 = b'&';
+
+    pub const BRICK: u8 = b'%';
     pub const FLAG: u8 = b'=';
     pub const GEM: u8 = b'*';
+
+    /// Allows bot carrying it to open a door ([`crate::TileKind::DOOR`]) -
+    /// the key gets used up in the process.
+    pub const KEY: u8 = b'/';
+
+    /// Explodes when a bot moves next to it, killing that bot.
+    pub const MINE: u8 = b'!';
+
+    /// Moves bot to another teleporter on the same channel, see
+    /// [`Object::channel()`].
+    pub const TELEPORTER: u8 = b'o';
 }
 
 #[derive(
@@ -1,8 +1,10 @@
-use crate::{Object, ObjectId};
-use ahash::AHashMap;
+use crate::{AliveBots, Object, ObjectId};
+use ahash::{AHashMap, AHashSet};
 use bevy_ecs::system::Resource;
 use glam::IVec2;
 use rand::{Rng, RngCore};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tracing::warn;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Resource)]
 pub struct Objects {
@@ -15,22 +17,39 @@ impl Objects {
     pub fn create(
         &mut self,
         rng: &mut impl RngCore,
+        bots: &AliveBots,
         obj: Object,
         pos: Option<IVec2>,
     ) -> ObjectId {
-        let id = loop {
-            let id = ObjectId(rng.gen());
-
-            if !self.objects.contains_key(&id) {
-                break id;
-            }
-        };
+        let id = self.create_id(rng, bots);
 
         self.add(id, obj, pos);
 
         id
     }
 
+    /// Generates a new id, without registering any object under it - useful
+    /// for objects that get created straight into a bot's inventory.
+    ///
+    /// The id is unique across both the objects registered here and the ones
+    /// carried by `bots` - bots that are temporarily taken out of `bots` (e.g.
+    /// during [`crate::bots::tick()`]) have to be checked by the caller.
+    pub fn create_id(
+        &self,
+        rng: &mut impl RngCore,
+        bots: &AliveBots,
+    ) -> ObjectId {
+        loop {
+            let id = ObjectId(rng.gen());
+
+            if !self.objects.contains_key(&id)
+                && !bots.iter().any(|bot| bot.inventory.contains(id))
+            {
+                break id;
+            }
+        }
+    }
+
     pub fn add(&mut self, id: ObjectId, obj: Object, pos: Option<IVec2>) {
         self.objects.insert(id, obj);
 
@@ -76,9 +95,50 @@ impl Objects {
             obj: *obj,
         })
     }
+
+    /// Removes objects that are at the same time carried by some bot.
+    ///
+    /// Carried objects live only in bots' inventories, so this shouldn't
+    /// happen - but if the world got saved in an inconsistent state, this
+    /// makes sure that we don't end up with two objects sharing the same id.
+    pub fn remove_carried(&mut self, bots: &AliveBots) {
+        let carried: AHashSet<_> =
+            bots.iter().flat_map(|bot| bot.inventory.ids()).collect();
+
+        for id in carried {
+            if self.remove(id).is_some() {
+                warn!(?id, "removed object that's also carried by a bot");
+            }
+        }
+    }
+}
+
+impl Serialize for Objects {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for Objects {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut this = Self::default();
+        let objects = Vec::<ObjectEntry>::deserialize(deserializer)?;
+
+        for object in objects {
+            this.add(object.id, object.obj, object.pos);
+        }
+
+        Ok(this)
+    }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct ObjectEntry {
     pub id: ObjectId,
     pub obj: Object,
@@ -1,16 +1,52 @@
-use crate::spec;
-use anyhow::{anyhow, Error, Result};
+mod spawn;
+
+pub use self::spawn::*;
+use crate::{spec, GameMode};
+use anyhow::{anyhow, Context, Error, Result};
 use bevy_ecs::system::Resource;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-#[derive(
-    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Resource,
-)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Resource)]
 pub struct Policy {
     pub auto_respawn: bool,
+
+    /// Number of hit points bots are born with - each stab takes away one of
+    /// them (see: [`crate::BotArm::STAB_DAMAGE`]); must be at least one.
+    pub bot_hp: u32,
+
+    /// Whether players see only the tiles their own bots have scanned with
+    /// radar (see: [`crate::Snapshot::fogged()`]).
+    pub fog: bool,
+
     pub max_alive_bots: usize,
     pub max_queued_bots: usize,
+    pub mode: GameMode,
+    pub spawn: SpawnPolicy,
+
+    /// Number of teams bots get split into - bots that don't ask for any
+    /// specific team join the smallest one; zero disables this.
+    pub teams: u8,
+
+    /// Whether bots can dig walls and build them back using the bricks they've
+    /// dug up.
+    pub terraforming: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            auto_respawn: false,
+            bot_hp: 1,
+            fog: false,
+            max_alive_bots: 0,
+            max_queued_bots: 0,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
+        }
+    }
 }
 
 impl FromStr for Policy {
@@ -18,6 +54,8 @@ impl FromStr for Policy {
 
     fn from_str(spec: &str) -> Result<Self> {
         let mut this = Self::default();
+        let mut bases = Vec::new();
+        let mut hill = None;
 
         for entry in spec::entries(spec) {
             let entry = entry?;
@@ -26,18 +64,80 @@ impl FromStr for Policy {
                 "auto-respawn" => {
                     this.auto_respawn = entry.value()?;
                 }
+                "bot-hp" => {
+                    this.bot_hp = entry.value()?;
+
+                    if this.bot_hp == 0 {
+                        return Err(anyhow!("`bot-hp` must be at least 1"));
+                    }
+                }
+                "fog" => {
+                    this.fog = entry.value()?;
+                }
                 "max-alive-bots" => {
                     this.max_alive_bots = entry.value()?;
                 }
                 "max-queued-bots" => {
                     this.max_queued_bots = entry.value()?;
                 }
+                "mode" => {
+                    this.mode = entry.value.parse()?;
+                }
+                "base" => {
+                    bases.push(entry.value.parse().with_context(|| {
+                        format!("couldn't parse `{}`", entry.key)
+                    })?);
+                }
+                "hill" => {
+                    hill = Some(entry.value.parse().with_context(|| {
+                        format!("couldn't parse `{}`", entry.key)
+                    })?);
+                }
+                "spawn-strategy" => {
+                    this.spawn.strategy = entry.value.parse()?;
+                }
+                "spawn-zone" => {
+                    this.spawn.zones.push(entry.value.parse().with_context(
+                        || format!("couldn't parse `{}`", entry.key),
+                    )?);
+                }
+                "teams" => {
+                    this.teams = entry.value()?;
+                }
+                "terraforming" => {
+                    this.terraforming = entry.value()?;
+                }
                 key => {
                     return Err(anyhow!("unknown key: {key}"));
                 }
             }
         }
 
+        match &mut this.mode {
+            GameMode::CaptureTheFlag { bases: this_bases } => {
+                if bases.is_empty() {
+                    return Err(anyhow!("capture-the-flag requires `base`"));
+                }
+
+                *this_bases = bases;
+            }
+
+            GameMode::KingOfTheHill { hill: this_hill } => {
+                *this_hill =
+                    hill.context("king-of-the-hill requires `hill`")?;
+            }
+
+            _ => {
+                if !bases.is_empty() {
+                    return Err(anyhow!("`base` requires capture-the-flag"));
+                }
+
+                if hill.is_some() {
+                    return Err(anyhow!("`hill` requires king-of-the-hill"));
+                }
+            }
+        }
+
         Ok(this)
     }
 }
@@ -45,20 +145,77 @@ impl FromStr for Policy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{GameZone, Team};
+    use glam::ivec2;
 
     #[test]
     fn from_str() {
         let actual = Policy::from_str(
-            "auto-respawn=true,max-alive-bots=100,max-queued-bots=200",
+            "auto-respawn=true,bot-hp=3,fog=true,max-alive-bots=100,\
+             max-queued-bots=200,spawn-strategy=round-robin,spawn-zone=a:1:2:3:4,\
+             spawn-zone=b:10:10:5:5:1,teams=2,terraforming=true",
         )
         .unwrap();
 
         let expected = Policy {
             auto_respawn: true,
+            bot_hp: 3,
+            fog: true,
             max_alive_bots: 100,
             max_queued_bots: 200,
+            mode: GameMode::Deathmatch,
+            spawn: SpawnPolicy {
+                zones: vec![
+                    SpawnZone::rect("a", ivec2(1, 2), ivec2(3, 4)),
+                    SpawnZone::rect("b", ivec2(5, 5), ivec2(10, 10))
+                        .with_team(Team::new(1)),
+                ],
+                strategy: SpawnStrategy::RoundRobin,
+            },
+            teams: 2,
+            terraforming: true,
         };
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn from_str_mode() {
+        let actual = Policy::from_str(
+            "mode=capture-the-flag,base=1:1:3:3:0,base=5:5:7:7",
+        )
+        .unwrap()
+        .mode;
+
+        let expected = GameMode::CaptureTheFlag {
+            bases: vec![
+                GameZone::new(ivec2(1, 1), ivec2(3, 3)).with_team(Team::new(0)),
+                GameZone::new(ivec2(5, 5), ivec2(7, 7)),
+            ],
+        };
+
+        assert_eq!(expected, actual);
+
+        let actual = Policy::from_str("mode=king-of-the-hill,hill=1:1:3:3")
+            .unwrap()
+            .mode;
+
+        let expected = GameMode::KingOfTheHill {
+            hill: GameZone::new(ivec2(1, 1), ivec2(3, 3)),
+        };
+
+        assert_eq!(expected, actual);
+
+        assert!(Policy::from_str("mode=capture-the-flag").is_err());
+        assert!(Policy::from_str("mode=king-of-the-hill").is_err());
+        assert!(Policy::from_str("mode=deathmatch,hill=1:1:3:3").is_err());
+        assert!(Policy::from_str("mode=tic-tac-toe").is_err());
+    }
+
+    #[test]
+    fn from_str_bot_hp() {
+        assert_eq!(1, Policy::default().bot_hp);
+        assert_eq!(1, Policy::from_str("").unwrap().bot_hp);
+        assert!(Policy::from_str("bot-hp=0").is_err());
+    }
 }
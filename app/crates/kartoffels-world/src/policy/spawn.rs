@@ -0,0 +1,209 @@
+use crate::Team;
+use anyhow::{anyhow, Context, Error, Result};
+use glam::{ivec2, IVec2, UVec2};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Determines where bots get spawned - unless the world's spawn point is set
+/// via [`crate::Handle::set_spawn()`] or bot requests a specific position.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnPolicy {
+    /// Areas bots get spawned in - if empty, bots get spawned at random places
+    /// on the map.
+    pub zones: Vec<SpawnZone>,
+
+    pub strategy: SpawnStrategy,
+}
+
+impl SpawnPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnZone {
+    pub name: String,
+    pub area: SpawnArea,
+
+    /// Team this zone belongs to, see [`SpawnStrategy::PerTeam`].
+    pub team: Option<Team>,
+}
+
+impl SpawnZone {
+    pub fn rect(name: impl ToString, min: IVec2, max: IVec2) -> Self {
+        Self {
+            name: name.to_string(),
+            area: SpawnArea::Rect { min, max },
+            team: None,
+        }
+    }
+
+    pub fn points(name: impl ToString, points: Vec<IVec2>) -> Self {
+        Self {
+            name: name.to_string(),
+            area: SpawnArea::Points { points },
+            team: None,
+        }
+    }
+
+    pub fn with_team(mut self, team: impl Into<Option<Team>>) -> Self {
+        self.team = team.into();
+        self
+    }
+
+    /// Returns positions within this zone, skipping the ones that lie outside
+    /// of a map of given size - so that a zone covering, say, `0..i32::MAX`
+    /// doesn't make us iterate over billions of tiles.
+    pub fn positions(
+        &self,
+        map_size: UVec2,
+    ) -> Box<dyn Iterator<Item = IVec2> + '_> {
+        match &self.area {
+            SpawnArea::Rect { min, max } => {
+                let min = min.max(IVec2::ZERO);
+                let max = max.min(map_size.as_ivec2() - 1);
+
+                Box::new((min.y..=max.y).flat_map(move |y| {
+                    (min.x..=max.x).map(move |x| ivec2(x, y))
+                }))
+            }
+
+            SpawnArea::Points { points } => {
+                let map_size = map_size.as_ivec2();
+
+                Box::new(points.iter().copied().filter(move |pos| {
+                    pos.cmpge(IVec2::ZERO).all() && pos.cmplt(map_size).all()
+                }))
+            }
+        }
+    }
+}
+
+impl FromStr for SpawnZone {
+    type Err = Error;
+
+    /// Parses zone from `name:x1:y1:x2:y2` or `name:x1:y1:x2:y2:team`, where
+    /// both corners are inclusive.
+    fn from_str(spec: &str) -> Result<Self> {
+        const EXPECTED: &str =
+            "expected `name:x1:y1:x2:y2` or `name:x1:y1:x2:y2:team`";
+
+        let (name, nums) = spec.split_once(':').context(EXPECTED)?;
+
+        let nums = nums
+            .split(':')
+            .map(|num| num.parse())
+            .collect::<Result<Vec<i32>, _>>()
+            .context("couldn't parse coordinates")?;
+
+        let (x1, y1, x2, y2, team) = match nums[..] {
+            [x1, y1, x2, y2] => (x1, y1, x2, y2, None),
+
+            [x1, y1, x2, y2, team] => {
+                let team = u8::try_from(team).context("invalid team")?;
+
+                (x1, y1, x2, y2, Some(Team::new(team)))
+            }
+
+            _ => {
+                return Err(anyhow!(EXPECTED));
+            }
+        };
+
+        let min = ivec2(x1, y1).min(ivec2(x2, y2));
+        let max = ivec2(x1, y1).max(ivec2(x2, y2));
+
+        Ok(Self::rect(name, min, max).with_team(team))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SpawnArea {
+    #[serde(rename = "rect")]
+    Rect { min: IVec2, max: IVec2 },
+
+    #[serde(rename = "points")]
+    Points { points: Vec<IVec2> },
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum SpawnStrategy {
+    /// Picks a random zone.
+    #[default]
+    #[serde(rename = "random")]
+    Random,
+
+    /// Goes through the zones in order, one zone per spawned bot.
+    #[serde(rename = "round-robin")]
+    RoundRobin,
+
+    /// Picks the tile that's the farthest from all the other bots.
+    #[serde(rename = "farthest")]
+    Farthest,
+
+    /// Picks a random zone belonging to bot's team - if there's no such zone
+    /// (or all of them are full), falls back to [`Self::Random`].
+    #[serde(rename = "per-team")]
+    PerTeam,
+}
+
+impl FromStr for SpawnStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "random" => Ok(Self::Random),
+            "round-robin" => Ok(Self::RoundRobin),
+            "farthest" => Ok(Self::Farthest),
+            "per-team" => Ok(Self::PerTeam),
+            _ => Err(anyhow!("unknown strategy: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::uvec2;
+
+    #[test]
+    fn positions() {
+        let zone = SpawnZone::rect("a", ivec2(-5, 1), ivec2(i32::MAX, 2));
+
+        assert_eq!(
+            vec![ivec2(0, 1), ivec2(1, 1), ivec2(2, 1)],
+            zone.positions(uvec2(3, 2)).collect::<Vec<_>>(),
+        );
+
+        let zone = SpawnZone::points(
+            "b",
+            vec![ivec2(-1, 0), ivec2(1, 1), ivec2(3, 0)],
+        );
+
+        assert_eq!(
+            vec![ivec2(1, 1)],
+            zone.positions(uvec2(3, 2)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            SpawnZone::rect("a", ivec2(1, 2), ivec2(3, 4)),
+            SpawnZone::from_str("a:3:4:1:2").unwrap(),
+        );
+
+        assert_eq!(
+            SpawnZone::rect("b", ivec2(1, 2), ivec2(3, 4))
+                .with_team(Team::new(1)),
+            SpawnZone::from_str("b:1:2:3:4:1").unwrap(),
+        );
+
+        assert!(SpawnZone::from_str("c:1:2:3").is_err());
+        assert!(SpawnZone::from_str("c:1:2:3:4:256").is_err());
+    }
+}
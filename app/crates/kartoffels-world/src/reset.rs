@@ -0,0 +1,48 @@
+mod systems;
+
+pub use self::systems::*;
+use crate::{BotId, BotLives, BotStatsPart, Team};
+use anyhow::Result;
+use bevy_ecs::event::Event;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+#[derive(Debug, Event)]
+pub struct RegenerateMap {
+    pub tx: Option<oneshot::Sender<Result<()>>>,
+}
+
+#[derive(Debug, Event)]
+pub struct ClearBots {
+    pub tx: Option<oneshot::Sender<Vec<BotStanding>>>,
+}
+
+/// Bot's final results, as returned by [`crate::Handle::clear_bots()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotStanding {
+    pub id: BotId,
+    pub team: Option<Team>,
+
+    /// Sum of scores across all of the bot's lives.
+    pub score: u32,
+
+    /// Best score achieved within a single life.
+    pub best_score: u32,
+
+    pub lives: u32,
+}
+
+impl BotStanding {
+    fn new(id: BotId, team: Option<Team>, lives: &BotLives) -> Self {
+        let scores: BotStatsPart =
+            lives.iter().map(|life| life.score).collect();
+
+        Self {
+            id,
+            team,
+            score: scores.sum,
+            best_score: scores.max,
+            lives: lives.len() as u32,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod clear_bots;
+mod regenerate_map;
+
+pub use self::clear_bots::*;
+pub use self::regenerate_map::*;
@@ -0,0 +1,45 @@
+use crate::{BotStanding, Bots, ClearBots, Fog, Lives, Stats};
+use bevy_ecs::event::EventMutator;
+use bevy_ecs::system::ResMut;
+use std::cmp::Reverse;
+use tracing::info;
+
+pub fn clear_bots(
+    mut bots: ResMut<Bots>,
+    mut fog: ResMut<Fog>,
+    mut lives: ResMut<Lives>,
+    mut stats: ResMut<Stats>,
+    mut events: EventMutator<ClearBots>,
+) {
+    for event in events.read() {
+        let Some(tx) = event.tx.take() else {
+            continue;
+        };
+
+        let mut standings: Vec<_> = lives
+            .entries
+            .iter()
+            .map(|(&id, lives)| {
+                let team = bots.alive.team_of(id).or_else(|| {
+                    bots.queued
+                        .iter()
+                        .find(|bot| bot.id == id)
+                        .and_then(|bot| bot.team)
+                });
+
+                BotStanding::new(id, team, lives)
+            })
+            .collect();
+
+        standings.sort_by_key(|bot| (Reverse(bot.score), bot.id));
+
+        info!(bots = standings.len(), "clearing bots");
+
+        *bots = Default::default();
+        *fog = Default::default();
+        *lives = Default::default();
+        *stats = Default::default();
+
+        _ = tx.send(standings);
+    }
+}
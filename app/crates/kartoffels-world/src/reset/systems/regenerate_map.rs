@@ -0,0 +1,182 @@
+use crate::mode::sample_free_pos;
+use crate::{
+    Bots, Clock, DeathReason, FlagHomes, Fog, KillBot, Map, MapBuilder,
+    Markers, Objects, RegenerateMap, Terrain, Theme, WorldRng,
+};
+use anyhow::{anyhow, Result};
+use bevy_ecs::event::{EventMutator, EventReader};
+use bevy_ecs::system::{Commands, Res, ResMut, Resource};
+use futures_util::FutureExt;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::thread::{self, JoinHandle};
+use tokio::sync::oneshot;
+use tracing::{info, Span};
+
+/// Map that's currently being built, see [`regenerate_map()`].
+#[derive(Default, Resource)]
+pub struct PendingMap {
+    pending: Option<Pending>,
+}
+
+/// Run condition that lets [`regenerate_map()`] skip ticks when there's
+/// nothing to do.
+pub fn is_map_requested(
+    mut events: EventReader<RegenerateMap>,
+    state: Res<PendingMap>,
+) -> bool {
+    // Events have to be read unconditionally, otherwise we'd see them again
+    // during the next tick
+    events.read().count() > 0 || state.pending.is_some()
+}
+
+struct Pending {
+    tx: oneshot::Sender<Result<()>>,
+    handle: JoinHandle<Result<Map>>,
+}
+
+/// Rebuilds the map from the world's theme, using a fresh seed.
+///
+/// Since building a map can take a while, it happens on a separate thread -
+/// the world keeps running on the old map in the meantime and swaps it only
+/// once the new one is ready (except for the manual clock, where we wait for
+/// the map straight away to keep tests deterministic).
+///
+/// Objects lying on the ground get moved onto places provided by the theme
+/// (or scattered randomly, if the theme doesn't provide enough places), while
+/// alive bots get moved to random places on the new map (bots that don't fit
+/// anywhere get killed, so that they can respawn later).
+#[allow(clippy::too_many_arguments)]
+pub fn regenerate_map(
+    mut state: ResMut<PendingMap>,
+    mut cmds: Commands,
+    clock: Res<Clock>,
+    theme: Option<Res<Theme>>,
+    mut bots: ResMut<Bots>,
+    mut flag_homes: ResMut<FlagHomes>,
+    mut fog: ResMut<Fog>,
+    mut map: ResMut<Map>,
+    mut markers: ResMut<Markers>,
+    mut objects: ResMut<Objects>,
+    mut rng: ResMut<WorldRng>,
+    mut terrain: ResMut<Terrain>,
+    mut events: EventMutator<RegenerateMap>,
+) {
+    for event in events.read() {
+        let Some(tx) = event.tx.take() else {
+            continue;
+        };
+
+        let Some(theme) = &theme else {
+            _ = tx.send(Err(anyhow!("world doesn't have a theme")));
+            continue;
+        };
+
+        if state.pending.is_some() {
+            _ = tx.send(Err(anyhow!("map is already being regenerated")));
+            continue;
+        }
+
+        info!("regenerating map");
+
+        let theme = (**theme).clone();
+        let mut map_rng = ChaCha8Rng::from_seed(rng.0.gen());
+        let span = Span::current();
+
+        let handle = thread::spawn(move || {
+            let _span = span.entered();
+
+            theme
+                .build(&mut map_rng, MapBuilder::detached())
+                .now_or_never()
+                .unwrap()
+        });
+
+        state.pending = Some(Pending { tx, handle });
+    }
+
+    let is_ready = state.pending.as_ref().is_some_and(|pending| {
+        pending.handle.is_finished() || matches!(*clock, Clock::Manual { .. })
+    });
+
+    if !is_ready {
+        return;
+    }
+
+    // Unwrap-safety: We've just checked that there's a pending map
+    let pending = state.pending.take().unwrap();
+    let new_map = pending.handle.join().expect("map-building thread crashed");
+
+    let new_map = match new_map {
+        Ok(map) => map,
+        Err(err) => {
+            _ = pending.tx.send(Err(err));
+            return;
+        }
+    };
+
+    // Unwrap-safety: Theme can't be removed once the world's been created
+    let theme = theme.unwrap();
+
+    *map = new_map;
+    *flag_homes = Default::default();
+    *fog = Default::default();
+    *markers = Default::default();
+    terrain.invalidate();
+
+    let mut lying: Vec<_> = objects
+        .iter()
+        .filter(|entry| entry.pos.is_some())
+        .map(|entry| (entry.id, entry.obj))
+        .collect();
+
+    for (id, _) in &lying {
+        objects.remove(*id);
+    }
+
+    for (obj, pos) in theme.objects() {
+        if let Some(idx) = lying.iter().position(|(_, lying)| *lying == obj) {
+            let (id, obj) = lying.remove(idx);
+
+            objects.add(id, obj, Some(pos));
+        } else {
+            objects.create(&mut rng.0, &bots.alive, obj, Some(pos));
+        }
+    }
+
+    let ids: Vec<_> = bots.alive.iter().map(|bot| bot.id).collect();
+    let alive: Vec<_> = ids
+        .into_iter()
+        .filter_map(|id| bots.alive.remove(id))
+        .collect();
+
+    for mut bot in alive {
+        bot.log(&clock, "map has been regenerated");
+
+        if let Some(pos) =
+            sample_free_pos(&mut rng.0, &map, &bots.alive, &objects)
+        {
+            bot.pos = pos;
+            bots.alive.add(*bot);
+        } else {
+            cmds.send_event(KillBot {
+                killed: Some(bot),
+                reason: DeathReason::KilledByAdmin,
+                killer: None,
+                msg: "couldn't find place on the regenerated map".into(),
+            });
+        }
+    }
+
+    // Objects that the theme didn't have any place for (e.g. gems dropped by
+    // bots) get scattered around, so that they don't disappear
+    for (id, obj) in lying {
+        if let Some(pos) =
+            sample_free_pos(&mut rng.0, &map, &bots.alive, &objects)
+        {
+            objects.add(id, obj, Some(pos));
+        }
+    }
+
+    _ = pending.tx.send(Ok(()));
+}
@@ -4,8 +4,9 @@ mod systems;
 pub use self::stream::*;
 pub use self::systems::*;
 use crate::{
-    BotEvent, BotId, BotLife, BotLives, BotStats, Clock, Dir, Map, Object,
-    ObjectId, Ticks,
+    BotDisplay, BotEvent, BotId, BotLife, BotLives, BotStats, Clock, Dir,
+    Explored, FogSnapshot, Map, Object, ObjectId, Owner, Team, TeamStats,
+    Ticks, Tile, TileKind,
 };
 use ahash::AHashMap;
 use bevy_ecs::system::Resource;
@@ -14,21 +15,153 @@ use itertools::Itertools;
 use prettytable::{row, Table};
 use serde::Serialize;
 use std::cmp::Reverse;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::watch;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct Snapshot {
     pub bots: BotsSnapshot,
     pub clock: Clock,
+
+    /// Present only in worlds with fog of war (see: [`Self::fogged()`]).
+    #[serde(skip)]
+    pub fog: Option<FogSnapshot>,
+
     pub lives: LivesSnapshot,
+
+    /// Map with bots and objects drawn on it.
     pub map: Map,
+
     pub objects: ObjectsSnapshot,
     pub stats: StatsSnapshot,
+
+    /// Map without bots and objects drawn on it.
     pub tiles: Map,
+
     pub version: u64,
+
+    #[serde(skip)]
+    fogged: FoggedSnapshots,
+}
+
+impl Snapshot {
+    /// Returns snapshot as seen by given player - in worlds with fog of war,
+    /// tiles that player's bots haven't scanned yet get replaced with void,
+    /// together with other players' bots and objects lying there; in other
+    /// worlds this is a no-op.
+    ///
+    /// Results are cached per player, so asking for the same snapshot many
+    /// times (e.g. from many sessions of the same player) is cheap.
+    pub fn fogged(self: &Arc<Self>, owner: Owner) -> Arc<Self> {
+        let Some(fog) = &self.fog else {
+            return self.clone();
+        };
+
+        self.fogged
+            .0
+            .lock()
+            .unwrap()
+            .entry(owner)
+            .or_insert_with(|| Arc::new(self.fog(owner, fog.get(owner))))
+            .clone()
+    }
+
+    fn fog(&self, owner: Owner, explored: Option<&Explored>) -> Self {
+        let is_explored =
+            |pos: IVec2| explored.is_some_and(|explored| explored.get(pos));
+
+        let tiles = self.tiles.clone().map(|pos, tile| {
+            if is_explored(pos) {
+                tile
+            } else {
+                Tile::new(TileKind::VOID)
+            }
+        });
+
+        let alive = AliveBotsSnapshot::new(
+            self.bots
+                .alive
+                .iter()
+                .filter(|bot| bot.owner == Some(owner) || is_explored(bot.pos))
+                .cloned()
+                .collect(),
+        );
+
+        let objects = ObjectsSnapshot {
+            objects: self
+                .objects
+                .iter()
+                .filter(|obj| obj.pos.is_none_or(is_explored))
+                .cloned()
+                .collect(),
+        };
+
+        Self {
+            map: Self::draw(&tiles, &alive, &objects),
+            bots: BotsSnapshot {
+                alive,
+                dead: self.bots.dead.clone(),
+                queued: self.bots.queued.clone(),
+            },
+            clock: self.clock.clone(),
+            fog: None,
+            lives: self.lives.clone(),
+            objects,
+            stats: self.stats.clone(),
+            tiles,
+            version: self.version,
+            fogged: Default::default(),
+        }
+    }
+
+    /// Draws bots and objects on top of given tiles.
+    pub(crate) fn draw(
+        tiles: &Map,
+        bots: &AliveBotsSnapshot,
+        objects: &ObjectsSnapshot,
+    ) -> Map {
+        let mut map = tiles.clone();
+
+        for (idx, bot) in bots.iter().enumerate() {
+            let tile = Tile {
+                kind: TileKind::BOT,
+                meta: [idx as u8, 0, 0],
+            };
+
+            let chevron_pos = bot.pos + bot.dir;
+
+            let chevron_tile = Tile {
+                kind: TileKind::BOT_CHEVRON,
+                meta: [idx as u8, u8::from(bot.dir), 0],
+            };
+
+            map.set(bot.pos, tile);
+
+            if !map.get(chevron_pos).is_bot() {
+                map.set(chevron_pos, chevron_tile);
+            }
+        }
+
+        for obj in objects.iter() {
+            if let Some(pos) = obj.pos {
+                map.set(pos, obj.obj.kind);
+            }
+        }
+
+        map
+    }
+}
+
+/// Snapshots as seen by particular players, see: [`Snapshot::fogged()`].
+#[derive(Debug, Default)]
+struct FoggedSnapshots(Mutex<AHashMap<Owner, Arc<Snapshot>>>);
+
+impl Clone for FoggedSnapshots {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
 }
 
 impl fmt::Display for Snapshot {
@@ -50,7 +183,7 @@ impl fmt::Display for Snapshot {
     }
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct BotsSnapshot {
     pub alive: AliveBotsSnapshot,
     pub dead: DeadBotsSnapshot,
@@ -81,6 +214,14 @@ impl BotsSnapshot {
     pub fn is_empty(&self) -> bool {
         self.alive.is_empty() && self.dead.is_empty() && self.queued.is_empty()
     }
+
+    pub fn team_of(&self, id: BotId) -> Option<Team> {
+        match self.get(id)? {
+            BotSnapshot::Alive(bot) => bot.team,
+            BotSnapshot::Dead(_) => None,
+            BotSnapshot::Queued(bot) => bot.team,
+        }
+    }
 }
 
 impl fmt::Display for BotsSnapshot {
@@ -102,7 +243,7 @@ pub enum BotSnapshot<'a> {
     Queued(&'a QueuedBotSnapshot),
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct AliveBotsSnapshot {
     entries: Vec<AliveBotSnapshot>,
     #[serde(with = "kartoffels_utils::serde::sorted_map")]
@@ -111,6 +252,32 @@ pub struct AliveBotsSnapshot {
 }
 
 impl AliveBotsSnapshot {
+    pub(crate) fn new(entries: Vec<AliveBotSnapshot>) -> Self {
+        let id_to_idx = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, bot)| (bot.id, idx as u8))
+            .collect();
+
+        let idx_by_scores = {
+            let mut idx: Vec<_> = (0..(entries.len() as u8)).collect();
+
+            idx.sort_unstable_by_key(|idx| {
+                let bot = &entries[*idx as usize];
+
+                (Reverse(bot.score), Reverse(bot.age), bot.id)
+            });
+
+            idx
+        };
+
+        Self {
+            entries,
+            id_to_idx,
+            idx_by_scores,
+        }
+    }
+
     pub fn get(&self, id: BotId) -> Option<&AliveBotSnapshot> {
         self.get_by_idx(*self.id_to_idx.get(&id)?)
     }
@@ -176,18 +343,25 @@ impl fmt::Display for AliveBotsSnapshot {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct AliveBotSnapshot {
     pub age: Ticks,
     pub dir: Dir,
+    pub display: BotDisplay,
     pub events: Arc<VecDeque<Arc<BotEvent>>>,
     pub id: BotId,
+
+    /// See: [`Snapshot::fogged()`].
+    #[serde(skip)]
+    pub owner: Option<Owner>,
+
     pub pos: IVec2,
     pub score: u32,
     pub serial: Arc<VecDeque<u32>>,
+    pub team: Option<Team>,
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct DeadBotsSnapshot {
     #[serde(with = "kartoffels_utils::serde::sorted_map")]
     entries: AHashMap<BotId, DeadBotSnapshot>,
@@ -215,13 +389,13 @@ impl DeadBotsSnapshot {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct DeadBotSnapshot {
     pub events: Arc<VecDeque<Arc<BotEvent>>>,
     pub serial: Arc<VecDeque<u32>>,
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct QueuedBotsSnapshot {
     #[serde(with = "kartoffels_utils::serde::sorted_map")]
     entries: AHashMap<BotId, QueuedBotSnapshot>,
@@ -237,15 +411,16 @@ impl QueuedBotsSnapshot {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct QueuedBotSnapshot {
     pub events: Arc<VecDeque<Arc<BotEvent>>>,
     pub place: u8,
     pub reincarnated: bool,
     pub serial: Arc<VecDeque<u32>>,
+    pub team: Option<Team>,
 }
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ObjectsSnapshot {
     objects: Vec<ObjectSnapshot>,
 }
@@ -260,14 +435,14 @@ impl ObjectsSnapshot {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ObjectSnapshot {
     pub id: ObjectId,
     pub obj: Object,
     pub pos: Option<IVec2>,
 }
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct LivesSnapshot {
     #[serde(with = "kartoffels_utils::serde::sorted_map")]
     entries: AHashMap<BotId, Arc<BotLives>>,
@@ -295,19 +470,31 @@ impl LivesSnapshot {
 
 pub type BotLifeSnapshot = BotLife;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct StatsSnapshot {
     #[serde(with = "kartoffels_utils::serde::sorted_map")]
     entries: Arc<AHashMap<BotId, BotStats>>,
+    teams: Arc<BTreeMap<Team, TeamStats>>,
 }
 
 impl StatsSnapshot {
     pub fn get(&self, id: BotId) -> Option<&BotStatsSnapshot> {
         self.entries.get(&id)
     }
+
+    pub fn team(&self, team: Team) -> Option<&TeamStatsSnapshot> {
+        self.teams.get(&team)
+    }
+
+    pub fn teams(
+        &self,
+    ) -> impl Iterator<Item = (Team, &TeamStatsSnapshot)> + '_ {
+        self.teams.iter().map(|(team, stats)| (*team, stats))
+    }
 }
 
 pub type BotStatsSnapshot = BotStats;
+pub type TeamStatsSnapshot = TeamStats;
 
 #[derive(Debug, Resource)]
 pub struct Snapshots {
@@ -1,13 +1,11 @@
 use crate::{
     AliveBotSnapshot, AliveBots, AliveBotsSnapshot, Bots, BotsSnapshot, Clock,
-    DeadBotSnapshot, DeadBots, DeadBotsSnapshot, Events, Lives, LivesSnapshot,
-    Map, ObjectSnapshot, Objects, ObjectsSnapshot, QueuedBotSnapshot,
-    QueuedBots, QueuedBotsSnapshot, Snapshot, Snapshots, Stats, StatsSnapshot,
-    Tile, TileKind,
+    DeadBotSnapshot, DeadBots, DeadBotsSnapshot, Events, Fog, Lives,
+    LivesSnapshot, Map, ObjectSnapshot, Objects, ObjectsSnapshot, Policy,
+    QueuedBotSnapshot, QueuedBots, QueuedBotsSnapshot, Snapshot, Snapshots,
+    Stats, StatsSnapshot,
 };
-use ahash::AHashMap;
 use bevy_ecs::system::{Local, Res, ResMut};
-use std::cmp::Reverse;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -31,9 +29,11 @@ pub fn send(
     mut bots: ResMut<Bots>,
     mut events: Option<ResMut<Events>>,
     clock: Res<Clock>,
+    fog: Res<Fog>,
     lives: Res<Lives>,
     map: Res<Map>,
     objects: Res<Objects>,
+    policy: Res<Policy>,
     snapshots: Res<Snapshots>,
     stats: Res<Stats>,
 ) {
@@ -52,25 +52,29 @@ pub fn send(
 
         let stats = StatsSnapshot {
             entries: stats.entries.clone(),
+            teams: stats.teams.clone(),
         };
 
         let lives = LivesSnapshot {
             entries: lives.entries.clone(),
         };
 
-        let map = prepare_map(&bots, &map, &objects);
         let objects = prepare_objects(&objects);
         let tiles = map.clone();
+        let map = Snapshot::draw(&tiles, &bots.alive, &objects);
+        let fog = policy.fog.then(|| fog.clone());
 
         Arc::new(Snapshot {
             bots,
             clock: clock.clone(),
+            fog,
             lives,
             map,
             objects,
             stats,
             tiles,
             version: state.version,
+            fogged: Default::default(),
         })
     };
 
@@ -95,37 +99,18 @@ fn prepare_alive_bots(
         .map(|bot| AliveBotSnapshot {
             age: bot.age(),
             dir: bot.dir,
+            display: bot.display,
             events: bot.events.snapshot(),
             id: bot.id,
+            owner: bot.owner,
             pos: bot.pos,
             score: lives.curr_score(bot.id),
             serial: bot.serial.snapshot(),
+            team: bot.team,
         })
         .collect();
 
-    let id_to_idx: AHashMap<_, _> = entries
-        .iter()
-        .enumerate()
-        .map(|(idx, bot)| (bot.id, idx as u8))
-        .collect();
-
-    let idx_by_scores = {
-        let mut idx: Vec<_> = (0..(entries.len() as u8)).collect();
-
-        idx.sort_unstable_by_key(|idx| {
-            let bot = &entries[*idx as usize];
-
-            (Reverse(bot.score), Reverse(bot.age), bot.id)
-        });
-
-        idx
-    };
-
-    AliveBotsSnapshot {
-        entries,
-        id_to_idx,
-        idx_by_scores,
-    }
+    AliveBotsSnapshot::new(entries)
 }
 
 fn prepare_dead_bots(bots: &mut DeadBots) -> DeadBotsSnapshot {
@@ -153,6 +138,7 @@ fn prepare_queued_bots(bots: &mut QueuedBots) -> QueuedBotsSnapshot {
                 place: entry.place + 1,
                 reincarnated: entry.bot.requeued,
                 serial: entry.bot.serial.snapshot(),
+                team: entry.bot.team,
             };
 
             (entry.bot.id, bot)
@@ -162,38 +148,6 @@ fn prepare_queued_bots(bots: &mut QueuedBots) -> QueuedBotsSnapshot {
     QueuedBotsSnapshot { entries }
 }
 
-fn prepare_map(bots: &BotsSnapshot, map: &Map, objects: &Objects) -> Map {
-    let mut map = map.clone();
-
-    for (idx, bot) in bots.alive.iter().enumerate() {
-        let tile = Tile {
-            kind: TileKind::BOT,
-            meta: [idx as u8, 0, 0],
-        };
-
-        let chevron_pos = bot.pos + bot.dir;
-
-        let chevron_tile = Tile {
-            kind: TileKind::BOT_CHEVRON,
-            meta: [idx as u8, u8::from(bot.dir), 0],
-        };
-
-        map.set(bot.pos, tile);
-
-        if !map.get(chevron_pos).is_bot() {
-            map.set(chevron_pos, chevron_tile);
-        }
-    }
-
-    for obj in objects.iter() {
-        if let Some(pos) = obj.pos {
-            map.set(pos, obj.obj.kind);
-        }
-    }
-
-    map
-}
-
 fn prepare_objects(objects: &Objects) -> ObjectsSnapshot {
     let objects = objects
         .iter()
@@ -1,13 +1,15 @@
-use crate::{BotId, BotLives, Bots, Clock, Lives};
+use crate::{BotId, BotLives, Bots, Clock, Lives, Team};
 use ahash::AHashMap;
 use bevy_ecs::system::{Local, Res, ResMut, Resource};
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 
 #[derive(Clone, Debug, Default, Resource)]
 pub struct Stats {
     pub entries: Arc<AHashMap<BotId, BotStats>>,
+    pub teams: Arc<BTreeMap<Team, TeamStats>>,
 }
 
 pub fn update(
@@ -20,6 +22,7 @@ pub fn update(
         return;
     }
 
+    let stats = &mut *stats;
     let entries = Arc::make_mut(&mut stats.entries);
 
     *entries = lives
@@ -28,6 +31,30 @@ pub fn update(
         .map(|(id, lives)| (*id, BotStats::new(&bots, lives, *id)))
         .collect();
 
+    let teams = Arc::make_mut(&mut stats.teams);
+
+    teams.clear();
+
+    let bots = bots
+        .alive
+        .iter()
+        .map(|bot| (bot.id, bot.team))
+        .chain(bots.queued.iter().map(|bot| (bot.id, bot.team)));
+
+    for (id, team) in bots {
+        let Some(team) = team else {
+            continue;
+        };
+
+        let team = teams.entry(team).or_default();
+
+        team.bots += 1;
+
+        if let Some(bot) = entries.get(&id) {
+            team.score += bot.scores.sum;
+        }
+    }
+
     *prev_run_at = Some(Instant::now());
 }
 
@@ -66,6 +93,15 @@ impl BotStats {
     }
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct TeamStats {
+    /// Number of alive and queued bots in the team.
+    pub bots: u32,
+
+    /// Sum of scores of all the team's bots, across all of their lives.
+    pub score: u32,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize)]
 pub struct BotStatsPart {
     pub sum: u32,
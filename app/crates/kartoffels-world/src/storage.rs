@@ -4,7 +4,10 @@ mod systems;
 
 use self::header::*;
 pub use self::systems::*;
-use crate::{Bots, Lives, Map, Policy, Theme};
+use crate::{
+    Bots, CpuRng, FlagHomes, Lives, Map, Markers, Objects, Policy, Terrain,
+    Theme,
+};
 use maybe_owned::MaybeOwned;
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
@@ -12,10 +15,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SerializedWorld<'a> {
     pub bots: MaybeOwned<'a, Bots>,
+    pub cpu_rng: MaybeOwned<'a, CpuRng>,
+    pub flag_homes: MaybeOwned<'a, FlagHomes>,
     pub lives: MaybeOwned<'a, Lives>,
     pub map: MaybeOwned<'a, Map>,
+    pub markers: MaybeOwned<'a, Markers>,
     pub name: MaybeOwned<'a, String>,
+    pub objects: MaybeOwned<'a, Objects>,
     pub policy: MaybeOwned<'a, Policy>,
     pub rng: MaybeOwned<'a, ChaCha8Rng>,
+    pub terrain: MaybeOwned<'a, Terrain>,
     pub theme: Option<MaybeOwned<'a, Theme>>,
 }
@@ -12,6 +12,26 @@ mod v12;
 mod v13;
 mod v14;
 mod v15;
+mod v16;
+mod v17;
+mod v18;
+mod v19;
+mod v20;
+mod v21;
+mod v22;
+mod v23;
+mod v24;
+mod v25;
+mod v26;
+mod v27;
+mod v28;
+mod v29;
+mod v30;
+mod v31;
+mod v32;
+mod v33;
+mod v34;
+mod v35;
 
 use anyhow::Result;
 use ciborium::Value;
@@ -32,6 +52,26 @@ static MIGRATIONS: &[fn(&mut Value)] = &[
     v13::run,
     v14::run,
     v15::run,
+    v16::run,
+    v17::run,
+    v18::run,
+    v19::run,
+    v20::run,
+    v21::run,
+    v22::run,
+    v23::run,
+    v24::run,
+    v25::run,
+    v26::run,
+    v27::run,
+    v28::run,
+    v29::run,
+    v30::run,
+    v31::run,
+    v32::run,
+    v33::run,
+    v34::run,
+    v35::run,
 ];
 
 pub fn run(old: u32, new: u32, mut world: Value) -> Result<Value> {
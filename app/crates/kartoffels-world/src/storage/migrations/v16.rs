@@ -0,0 +1,73 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    world
+        .query_mut("/policy")
+        .next()
+        .unwrap()
+        .as_map_mut()
+        .unwrap()
+        .add_entry("bot_hp", Value::Integer(1.into()));
+
+    for bot in world.query_mut("/bots/alive/*") {
+        bot.as_map_mut().unwrap().add_entry(
+            "health",
+            Value::Map(
+                Vec::default()
+                    .with_entry("hp", Value::Integer(1.into()))
+                    .with_entry("max_hp", Value::Integer(1.into())),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234"
+                }
+              ]
+            },
+            "policy": {
+              "auto_respawn": true,
+              "max_alive_bots": 16,
+              "max_queued_bots": 32
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234",
+                  "health": {
+                    "hp": 1,
+                    "max_hp": 1
+                  }
+                }
+              ]
+            },
+            "policy": {
+              "auto_respawn": true,
+              "bot_hp": 1,
+              "max_alive_bots": 16,
+              "max_queued_bots": 32
+            }
+          }
+        "#};
+
+        migrations::tests::run(16, given, expected);
+    }
+}
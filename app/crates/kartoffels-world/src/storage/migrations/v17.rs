@@ -0,0 +1,54 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for bot in world.query_mut("/bots/alive/*") {
+        bot.as_map_mut().unwrap().add_entry(
+            "display",
+            Value::Map(
+                Vec::default()
+                    .with_entry("glyph", Value::Null)
+                    .with_entry("color", Value::Integer(0.into())),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234"
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234",
+                  "display": {
+                    "glyph": null,
+                    "color": 0
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(17, given, expected);
+    }
+}
@@ -0,0 +1,64 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for bot in world.query_mut("/bots/{alive,queued}/*") {
+        bot.as_map_mut().unwrap().add_entry(
+            "nvram",
+            Value::Map(Vec::default().with_entry("mem", Value::Array(vec![]))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234"
+                }
+              ],
+
+              "queued": [
+                {
+                  "id": "4321-4321-4321-4321"
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": "1234-1234-1234-1234",
+                  "nvram": {
+                    "mem": []
+                  }
+                }
+              ],
+
+              "queued": [
+                {
+                  "id": "4321-4321-4321-4321",
+                  "nvram": {
+                    "mem": []
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(18, given, expected);
+    }
+}
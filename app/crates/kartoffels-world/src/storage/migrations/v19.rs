@@ -0,0 +1,64 @@
+use ciborium::value::Integer;
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for radar in world.query_mut("/bots/alive/*/radar") {
+        radar.as_map_mut().unwrap().add_entry(
+            "markers",
+            Value::Array(vec![Value::Integer(Integer::from(0)); 9 * 9]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "radar": {
+                    "scan": [1, 2, 3],
+                    "cooldown": 123
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "radar": {
+                    "scan": [1, 2, 3],
+                    "cooldown": 123,
+                    "markers": [
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0,
+                      0, 0, 0, 0, 0, 0, 0, 0, 0
+                    ]
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(19, given, expected);
+    }
+}
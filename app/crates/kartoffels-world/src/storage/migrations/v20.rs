@@ -0,0 +1,52 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for motor in world.query_mut("/bots/alive/*/motor") {
+        let motor = motor.as_map_mut().unwrap();
+
+        motor.add_entry("last_move", Value::Integer(0.into()));
+        motor.add_entry("steps", Value::Integer(0.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "motor": {
+                    "cooldown": 123
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "motor": {
+                    "cooldown": 123,
+                    "last_move": 0,
+                    "steps": 0
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(20, given, expected);
+    }
+}
@@ -0,0 +1,54 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for timer in world.query_mut("/bots/alive/*/timer") {
+        let timer = timer.as_map_mut().unwrap();
+
+        timer.add_entry("alarm", Value::Null);
+        timer.add_entry("alarm_hi", Value::Integer(0.into()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "timer": {
+                    "seed": 123,
+                    "ticks": 456
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "timer": {
+                    "seed": 123,
+                    "ticks": 456,
+                    "alarm": null,
+                    "alarm_hi": 0
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(21, given, expected);
+    }
+}
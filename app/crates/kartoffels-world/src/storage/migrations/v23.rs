@@ -0,0 +1,33 @@
+use ciborium::Value;
+use kartoffels_utils::CborMapExt;
+
+pub fn run(world: &mut Value) {
+    world
+        .as_map_mut()
+        .unwrap()
+        .add_entry("objects", Value::Array(Default::default()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "name": "world"
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "name": "world",
+            "objects": []
+          }
+        "#};
+
+        migrations::tests::run(23, given, expected);
+    }
+}
@@ -0,0 +1,52 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    world
+        .query_mut("/policy")
+        .next()
+        .unwrap()
+        .as_map_mut()
+        .unwrap()
+        .add_entry(
+            "spawn",
+            Value::Map(
+                Vec::default()
+                    .with_entry("zones", Value::Array(Default::default()))
+                    .with_entry("strategy", Value::Text("random".into())),
+            ),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "policy": {
+              "max_alive_bots": 16,
+              "max_queued_bots": 32
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "policy": {
+              "max_alive_bots": 16,
+              "max_queued_bots": 32,
+              "spawn": {
+                "strategy": "random",
+                "zones": []
+              }
+            }
+          }
+        "#};
+
+        migrations::tests::run(24, given, expected);
+    }
+}
@@ -0,0 +1,74 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for bot in world.query_mut("/bots/alive/*") {
+        bot.as_map_mut().unwrap().add_entry("team", Value::Null);
+    }
+
+    for bot in world.query_mut("/bots/queued/*") {
+        bot.as_map_mut().unwrap().add_entry("team", Value::Null);
+    }
+
+    world
+        .query_mut("/policy")
+        .next()
+        .unwrap()
+        .as_map_mut()
+        .unwrap()
+        .add_entry("teams", Value::Integer(0.into()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": 1
+                }
+              ],
+              "queued": [
+                {
+                  "id": 2
+                }
+              ]
+            },
+            "policy": {
+              "max_alive_bots": 16
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": 1,
+                  "team": null
+                }
+              ],
+              "queued": [
+                {
+                  "id": 2,
+                  "team": null
+                }
+              ]
+            },
+            "policy": {
+              "max_alive_bots": 16,
+              "teams": 0
+            }
+          }
+        "#};
+
+        migrations::tests::run(25, given, expected);
+    }
+}
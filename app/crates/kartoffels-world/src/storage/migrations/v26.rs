@@ -0,0 +1,50 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    world
+        .query_mut("/policy")
+        .next()
+        .unwrap()
+        .as_map_mut()
+        .unwrap()
+        .add_entry(
+            "mode",
+            Value::Map(
+                Vec::default()
+                    .with_entry("type", Value::Text("deathmatch".into())),
+            ),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "policy": {
+              "max_alive_bots": 16,
+              "max_queued_bots": 32
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "policy": {
+              "max_alive_bots": 16,
+              "max_queued_bots": 32,
+              "mode": {
+                "type": "deathmatch"
+              }
+            }
+          }
+        "#};
+
+        migrations::tests::run(26, given, expected);
+    }
+}
@@ -0,0 +1,39 @@
+use ciborium::Value;
+use kartoffels_utils::CborMapExt;
+
+pub fn run(world: &mut Value) {
+    world.as_map_mut().unwrap().add_entry(
+        "cpu_rng",
+        Value::Map(vec![
+            (Value::Text("seed".into()), Value::Null),
+            (Value::Text("tick".into()), Value::Integer(0.into())),
+        ]),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "name": "world"
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "name": "world",
+            "cpu_rng": {
+              "seed": null,
+              "tick": 0
+            }
+          }
+        "#};
+
+        migrations::tests::run(28, given, expected);
+    }
+}
@@ -0,0 +1,42 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    world
+        .query_mut("/policy")
+        .next()
+        .unwrap()
+        .as_map_mut()
+        .unwrap()
+        .add_entry("terraforming", Value::Bool(false));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "policy": {
+              "bot_hp": 1,
+              "max_alive_bots": 16
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "policy": {
+              "bot_hp": 1,
+              "max_alive_bots": 16,
+              "terraforming": false
+            }
+          }
+        "#};
+
+        migrations::tests::run(29, given, expected);
+    }
+}
@@ -0,0 +1,67 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    world
+        .as_map_mut()
+        .unwrap()
+        .add_entry("markers", Value::Array(Default::default()));
+
+    for bot in world.query_mut("/bots/alive/*") {
+        bot.as_map_mut().unwrap().add_entry(
+            "marker",
+            Value::Map(vec![(
+                Value::Text("cooldown".into()),
+                Value::Integer(0.into()),
+            )]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": 123
+                },
+                {
+                  "id": 456
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "id": 123,
+                  "marker": {
+                    "cooldown": 0
+                  }
+                },
+                {
+                  "id": 456,
+                  "marker": {
+                    "cooldown": 0
+                  }
+                }
+              ]
+            },
+            "markers": []
+          }
+        "#};
+
+        migrations::tests::run(30, given, expected);
+    }
+}
@@ -0,0 +1,53 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for health in world.query_mut("/bots/alive/*/health") {
+        health
+            .as_map_mut()
+            .unwrap()
+            .add_entry("last_hit_by", Value::Null);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "health": {
+                    "hp": 2,
+                    "max_hp": 3
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "bots": {
+              "alive": [
+                {
+                  "health": {
+                    "hp": 2,
+                    "max_hp": 3,
+                    "last_hit_by": null
+                  }
+                }
+              ]
+            }
+          }
+        "#};
+
+        migrations::tests::run(31, given, expected);
+    }
+}
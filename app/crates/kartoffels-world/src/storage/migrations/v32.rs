@@ -0,0 +1,33 @@
+use ciborium::Value;
+use kartoffels_utils::CborMapExt;
+
+pub fn run(world: &mut Value) {
+    world
+        .as_map_mut()
+        .unwrap()
+        .add_entry("flag_homes", Value::Map(Default::default()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "objects": []
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "objects": [],
+            "flag_homes": {}
+          }
+        "#};
+
+        migrations::tests::run(32, given, expected);
+    }
+}
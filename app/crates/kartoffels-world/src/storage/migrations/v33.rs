@@ -0,0 +1,42 @@
+use ciborium::Value;
+use kartoffels_utils::CborMapExt;
+
+pub fn run(world: &mut Value) {
+    world.as_map_mut().unwrap().add_entry(
+        "terrain",
+        Value::Map(vec![
+            (
+                Value::Text("exposures".into()),
+                Value::Map(Default::default()),
+            ),
+            (Value::Text("plates".into()), Value::Map(Default::default())),
+        ]),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "objects": []
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "objects": [],
+            "terrain": {
+              "exposures": {},
+              "plates": {}
+            }
+          }
+        "#};
+
+        migrations::tests::run(33, given, expected);
+    }
+}
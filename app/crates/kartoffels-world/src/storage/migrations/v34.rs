@@ -0,0 +1,60 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    for zone in world.query_mut("/policy/spawn/zones/*") {
+        zone.as_map_mut().unwrap().add_entry("team", Value::Null);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "policy": {
+              "spawn": {
+                "zones": [
+                  {
+                    "name": "a",
+                    "area": {
+                      "type": "rect",
+                      "min": [1, 2],
+                      "max": [3, 4]
+                    }
+                  }
+                ],
+                "strategy": "random"
+              }
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "policy": {
+              "spawn": {
+                "zones": [
+                  {
+                    "name": "a",
+                    "area": {
+                      "type": "rect",
+                      "min": [1, 2],
+                      "max": [3, 4]
+                    },
+                    "team": null
+                  }
+                ],
+                "strategy": "random"
+              }
+            }
+          }
+        "#};
+
+        migrations::tests::run(34, given, expected);
+    }
+}
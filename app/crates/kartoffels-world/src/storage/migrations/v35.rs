@@ -0,0 +1,77 @@
+use ciborium::Value;
+use kartoffels_utils::{CborMapExt, CborValueExt};
+
+pub fn run(world: &mut Value) {
+    let Some(lives) = world.query_mut("/lives").next() else {
+        return;
+    };
+
+    for (_, lives) in lives.as_map_mut().unwrap() {
+        for query in ["/curr", "/prev/*"] {
+            for life in lives.query_mut(query) {
+                life.as_map_mut().unwrap().add_entry("team", Value::Null);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migrations;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let given = indoc! {r#"
+          {
+            "lives": {
+              "1234-1234-1234-1234": {
+                "curr": {
+                  "score": 0,
+                  "born_at": "2018-01-01T12:00:00Z"
+                },
+                "prev": [
+                  {
+                    "age": 10,
+                    "score": 1,
+                    "born_at": "2018-01-01T12:00:00Z",
+                    "died_at": "2018-01-01T12:10:00Z",
+                    "reason": null,
+                    "killer": null
+                  }
+                ],
+                "len": 1
+              }
+            }
+          }
+        "#};
+
+        let expected = indoc! {r#"
+          {
+            "lives": {
+              "1234-1234-1234-1234": {
+                "curr": {
+                  "score": 0,
+                  "born_at": "2018-01-01T12:00:00Z",
+                  "team": null
+                },
+                "prev": [
+                  {
+                    "age": 10,
+                    "score": 1,
+                    "born_at": "2018-01-01T12:00:00Z",
+                    "died_at": "2018-01-01T12:10:00Z",
+                    "reason": null,
+                    "killer": null,
+                    "team": null
+                  }
+                ],
+                "len": 1
+              }
+            }
+          }
+        "#};
+
+        migrations::tests::run(35, given, expected);
+    }
+}
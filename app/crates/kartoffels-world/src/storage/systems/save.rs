@@ -1,7 +1,7 @@
 use crate::storage::Header;
 use crate::{
-    Bots, Lives, Map, Metronome, Policy, SerializedWorld, Shutdown, Theme,
-    WorldName, WorldPath, WorldRng,
+    Bots, CpuRng, FlagHomes, Lives, Map, Markers, Metronome, Objects, Policy,
+    SerializedWorld, Shutdown, Terrain, Theme, WorldName, WorldPath, WorldRng,
 };
 use anyhow::Context;
 use bevy_ecs::system::{Local, Res};
@@ -29,13 +29,18 @@ impl Default for State {
 pub fn save(
     mut state: Local<State>,
     bots: Res<Bots>,
+    cpu_rng: Res<CpuRng>,
+    flag_homes: Res<FlagHomes>,
     lives: Res<Lives>,
     map: Res<Map>,
+    markers: Res<Markers>,
     name: Res<WorldName>,
+    objects: Res<Objects>,
     path: Option<Res<WorldPath>>,
     policy: Res<Policy>,
     rng: Res<WorldRng>,
     shutdown: Option<Res<Shutdown>>,
+    terrain: Res<Terrain>,
     theme: Option<Res<Theme>>,
 ) {
     let Some(path) = path else {
@@ -70,10 +75,15 @@ pub fn save(
 
     let world = SerializedWorld {
         bots: MaybeOwned::Borrowed(&bots),
+        cpu_rng: MaybeOwned::Borrowed(&cpu_rng),
+        flag_homes: MaybeOwned::Borrowed(&flag_homes),
         map: MaybeOwned::Borrowed(&map),
+        markers: MaybeOwned::Borrowed(&markers),
         name: MaybeOwned::Owned(name.0.load().to_string()),
+        objects: MaybeOwned::Borrowed(&objects),
         policy: MaybeOwned::Borrowed(&policy),
         rng: MaybeOwned::Borrowed(&rng.0),
+        terrain: MaybeOwned::Borrowed(&terrain),
         lives: MaybeOwned::Borrowed(&lives),
         theme: theme.as_ref().map(|theme| MaybeOwned::Borrowed(&**theme)),
     };
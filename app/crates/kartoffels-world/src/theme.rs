@@ -1,11 +1,18 @@
 mod arena;
 mod cave;
+mod custom;
+mod dungeon;
+mod maze;
 
 pub use self::arena::*;
 pub use self::cave::*;
-use crate::{Map, MapBuilder};
+pub use self::custom::*;
+pub use self::dungeon::*;
+pub use self::maze::*;
+use crate::{Map, MapBuilder, Object, SpawnZone};
 use anyhow::{anyhow, Error, Result};
 use bevy_ecs::system::Resource;
+use glam::IVec2;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -18,6 +25,15 @@ pub enum Theme {
 
     #[serde(rename = "cave")]
     Cave(CaveTheme),
+
+    #[serde(rename = "custom")]
+    Custom(CustomTheme),
+
+    #[serde(rename = "dungeon")]
+    Dungeon(DungeonTheme),
+
+    #[serde(rename = "maze")]
+    Maze(MazeTheme),
 }
 
 impl Theme {
@@ -29,6 +45,26 @@ impl Theme {
         match self {
             Theme::Arena(this) => this.build(rng, map).await,
             Theme::Cave(this) => this.build(rng, map).await,
+            Theme::Custom(this) => this.build(rng, map).await,
+            Theme::Dungeon(this) => this.build(rng, map).await,
+            Theme::Maze(this) => this.build(rng, map).await,
+        }
+    }
+
+    /// Returns spawn zones defined by the map itself - those get appended to
+    /// [`crate::SpawnPolicy::zones`] when the world is created.
+    pub fn spawn_zones(&self) -> Vec<SpawnZone> {
+        match self {
+            Theme::Custom(this) => this.spawn_zones(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns objects that should be placed on a freshly-built map.
+    pub fn objects(&self) -> Vec<(Object, IVec2)> {
+        match self {
+            Theme::Custom(this) => this.objects(),
+            _ => Vec::new(),
         }
     }
 }
@@ -45,6 +81,14 @@ impl FromStr for Theme {
             return CaveTheme::from_str(spec).map(Theme::Cave);
         }
 
+        if let Some(spec) = spec.strip_prefix("dungeon:") {
+            return DungeonTheme::from_str(spec).map(Theme::Dungeon);
+        }
+
+        if let Some(spec) = spec.strip_prefix("maze:") {
+            return MazeTheme::from_str(spec).map(Theme::Maze);
+        }
+
         Err(anyhow!("unknown theme"))
     }
 }
@@ -65,5 +109,20 @@ mod tests {
             Theme::Cave(CaveTheme::new(uvec2(12, 34))),
             Theme::from_str("cave:width=12,height=34").unwrap(),
         );
+
+        assert_eq!(
+            Theme::Dungeon(DungeonTheme::new(uvec2(64, 32))),
+            Theme::from_str("dungeon:width=64,height=32").unwrap(),
+        );
+
+        assert_eq!(
+            Theme::Maze(MazeTheme::new(uvec2(40, 20))),
+            Theme::from_str("maze:width=40,height=20").unwrap(),
+        );
+
+        assert_eq!(
+            Theme::Maze(MazeTheme::new(uvec2(40, 20)).with_loops(10)),
+            Theme::from_str("maze:width=40,height=20,loops=0.1").unwrap(),
+        );
     }
 }
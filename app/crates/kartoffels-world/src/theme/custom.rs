@@ -0,0 +1,200 @@
+use super::{Map, MapBuilder};
+use crate::{Dir, Object, ObjectKind, SpawnZone, Tile, TileKind};
+use anyhow::{anyhow, Context, Result};
+use glam::{ivec2, IVec2};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Theme built out of a hand-drawn ASCII map.
+///
+/// Tiles are taken as-is (see [`Map::parse()`]), except for:
+///
+/// - letters, which mark spawn points (the tile underneath becomes floor) -
+///   all points marked with the same letter form a spawn zone named after it,
+/// - object glyphs (e.g. `*` for [`ObjectKind::GEM`]), which place given
+///   object on the floor,
+/// - arrows (`^`, `>`, `v` and `<`), which place [`TileKind::CONVEYOR`]
+///   pushing bots in given direction - so `v` doesn't mark a spawn point,
+/// - doors, switches and pressure plates, which all get wired together onto
+///   the same channel (see: [`Tile::channel()`]).
+///
+/// Teleporters can't be placed this way, since their glyph is a letter.
+///
+/// The map is embedded into the theme (and so into the world's save file), so
+/// later changes to the original file don't affect already-created worlds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTheme {
+    map: String,
+}
+
+impl CustomTheme {
+    const CHANNEL: u8 = 1;
+
+    const OBJECTS: [u8; 6] = [
+        ObjectKind::BATTERY,
+        ObjectKind::BRICK,
+        ObjectKind::FLAG,
+        ObjectKind::GEM,
+        ObjectKind::KEY,
+        ObjectKind::MINE,
+    ];
+
+    pub fn new(map: impl Into<String>) -> Result<Self> {
+        let map = map.into().replace('\r', "");
+        let map = map.trim_end_matches('\n').to_owned();
+
+        if map.is_empty() {
+            return Err(anyhow!("map is empty"));
+        }
+
+        if !map.is_ascii() {
+            return Err(anyhow!("map must consist of ascii characters only"));
+        }
+
+        Ok(Self { map })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let map = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read `{}`", path.display()))?;
+
+        Self::new(map)
+    }
+
+    pub async fn build(
+        &self,
+        rng: &mut impl RngCore,
+        mut map: MapBuilder,
+    ) -> Result<Map> {
+        map.reveal(rng, self.parse().map).await;
+
+        Ok(map.commit())
+    }
+
+    pub fn spawn_zones(&self) -> Vec<SpawnZone> {
+        self.parse()
+            .spawns
+            .into_iter()
+            .map(|(name, points)| SpawnZone::points(name, points))
+            .collect()
+    }
+
+    pub fn objects(&self) -> Vec<(Object, IVec2)> {
+        self.parse().objects
+    }
+
+    fn conveyor(ch: u8) -> Option<Dir> {
+        match ch {
+            b'^' => Some(Dir::N),
+            b'>' => Some(Dir::E),
+            b'v' => Some(Dir::S),
+            b'<' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    fn parse(&self) -> ParsedMap {
+        let (mut map, _) = Map::parse(&self.map);
+        let mut spawns = BTreeMap::<_, Vec<_>>::new();
+        let mut objects = Vec::new();
+
+        for (y, line) in self.map.split('\n').enumerate() {
+            for (x, ch) in line.bytes().enumerate() {
+                let pos = ivec2(x as i32, y as i32);
+
+                if let Some(dir) = Self::conveyor(ch) {
+                    map.set(pos, Tile::conveyor(dir));
+                    continue;
+                }
+
+                if let TileKind::DOOR
+                | TileKind::DOOR_OPEN
+                | TileKind::PLATE
+                | TileKind::SWITCH = ch
+                {
+                    map.get_mut(pos).meta[0] = Self::CHANNEL;
+                    continue;
+                }
+
+                if ch.is_ascii_alphabetic() {
+                    spawns.entry(ch as char).or_default().push(pos);
+                } else if Self::OBJECTS.contains(&ch) {
+                    objects.push((Object::new(ch), pos));
+                } else {
+                    continue;
+                }
+
+                map.set(pos, TileKind::FLOOR);
+            }
+        }
+
+        ParsedMap {
+            map,
+            spawns,
+            objects,
+        }
+    }
+}
+
+struct ParsedMap {
+    map: Map,
+    spawns: BTreeMap<char, Vec<IVec2>>,
+    objects: Vec<(Object, IVec2)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use indoc::indoc;
+    use kartoffels_utils::Asserter;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    const MAP: &str = indoc! {"
+        -------
+        |A.*.B|
+        |.|.|.|
+        |..!..|
+        -------
+    "};
+
+    #[test]
+    fn build() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let map = CustomTheme::new(MAP)
+            .unwrap()
+            .build(&mut rng, MapBuilder::detached())
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        Asserter::new("src/theme/custom/tests")
+            .assert("build.txt", map.to_string());
+    }
+
+    #[test]
+    fn spawns_and_objects() {
+        let theme = CustomTheme::new(MAP).unwrap();
+
+        assert_eq!(
+            vec![
+                SpawnZone::points("A", vec![ivec2(1, 1)]),
+                SpawnZone::points("B", vec![ivec2(5, 1)]),
+            ],
+            theme.spawn_zones(),
+        );
+
+        assert_eq!(
+            vec![
+                (Object::new(ObjectKind::GEM), ivec2(3, 1)),
+                (Object::new(ObjectKind::MINE), ivec2(3, 3)),
+            ],
+            theme.objects(),
+        );
+    }
+}
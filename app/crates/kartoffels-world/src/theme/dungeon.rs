@@ -0,0 +1,446 @@
+use super::{Map, MapBuilder};
+use crate::{spec, Dir, Tile, TileKind};
+use ahash::AHashSet;
+use anyhow::{anyhow, Context, Error, Result};
+use glam::{ivec2, uvec2, IVec2, UVec2};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::iter;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DungeonTheme {
+    size: UVec2,
+}
+
+impl DungeonTheme {
+    /// Marks tiles belonging to rooms' walls during generation, so that
+    /// corridors know where to put doors instead of merging into the walls.
+    const ROOM_WALL: u8 = 1;
+
+    /// Marks corridors' walls knocked through by other corridors during
+    /// generation, so that we can tell crossings from corridors running along
+    /// (and breaking) each other's walls.
+    const OPENING: u8 = 2;
+
+    /// How likely each door is to be closed - such doors get pressure plates
+    /// on both sides, so that bots can pass through them without a key.
+    const CLOSED_DOOR_PROBABILITY: f64 = 0.3;
+
+    pub fn new(size: UVec2) -> Self {
+        Self { size }
+    }
+
+    pub async fn build(
+        &self,
+        rng: &mut impl RngCore,
+        mut map: MapBuilder,
+    ) -> Result<Map> {
+        let mut rng = {
+            let mut idx = 0;
+
+            loop {
+                map.set_status(format!("evaluating-subseed:{idx}"));
+                map.notify().await;
+
+                idx += 1;
+
+                if idx > 1024 {
+                    return Err(anyhow!(
+                        "couldn't generate dungeon - is the map too small?"
+                    ));
+                }
+
+                // ---
+
+                let sample_rng = ChaCha8Rng::from_seed(rng.gen());
+
+                let sample_map = self
+                    .build_once(&mut sample_rng.clone(), MapBuilder::detached())
+                    .await;
+
+                if sample_map.is_some() {
+                    break sample_rng;
+                }
+            }
+        };
+
+        // Unwrap-safety: We're using the same seed as the sample above, so
+        // we're going to get the same (good enough) map
+        Ok(self.build_once(&mut rng, map).await.unwrap())
+    }
+
+    /// Builds the dungeon, returning `None` if it's not good enough (see:
+    /// [`Self::is_good_enough()`]).
+    async fn build_once(
+        &self,
+        rng: &mut impl RngCore,
+        mut map: MapBuilder,
+    ) -> Option<Map> {
+        map.begin(self.size);
+
+        let rooms = self.rooms_pass(rng, &mut map).await;
+
+        self.corridors_pass(rng, &mut map, &rooms).await;
+
+        if !map.with(|map| self.is_good_enough(map)) {
+            return None;
+        }
+
+        map.with(|map| {
+            map.for_each_mut(|_, tile| {
+                tile.meta[0] = 0;
+            });
+        });
+
+        self.doors_pass(rng, &mut map).await;
+
+        Some(map.commit())
+    }
+
+    async fn rooms_pass(
+        &self,
+        rng: &mut impl RngCore,
+        map: &mut MapBuilder,
+    ) -> Vec<Room> {
+        map.set_status("rooms-pass");
+        map.set_notify_every(25);
+
+        let mut rooms: Vec<Room> = Vec::new();
+
+        if self.size.x < 7 || self.size.y < 6 {
+            return rooms;
+        }
+
+        for _ in 0..(self.size.x * self.size.y / 32) {
+            let size = uvec2(
+                rng.gen_range(4..=12.min(self.size.x - 3)),
+                rng.gen_range(3..=6.min(self.size.y - 3)),
+            );
+
+            let min = uvec2(
+                rng.gen_range(1..=self.size.x - size.x - 1),
+                rng.gen_range(1..=self.size.y - size.y - 1),
+            );
+
+            let room = Room {
+                min: min.as_ivec2(),
+                max: (min + size).as_ivec2() - 1,
+            };
+
+            if rooms.iter().any(|other| room.collides_with(other)) {
+                continue;
+            }
+
+            for y in (room.min.y - 1)..=(room.max.y + 1) {
+                for x in (room.min.x - 1)..=(room.max.x + 1) {
+                    let pos = ivec2(x, y);
+
+                    let tile = if room.contains(pos) {
+                        TileKind::FLOOR
+                    } else if y == room.min.y - 1 || y == room.max.y + 1 {
+                        TileKind::WALL_H
+                    } else {
+                        TileKind::WALL_V
+                    };
+
+                    map.set(pos, tile).await;
+
+                    if !room.contains(pos) {
+                        map.with(|map| {
+                            map.get_mut(pos).meta[0] = Self::ROOM_WALL;
+                        });
+                    }
+                }
+            }
+
+            rooms.push(room);
+        }
+
+        rooms
+    }
+
+    async fn corridors_pass(
+        &self,
+        rng: &mut impl RngCore,
+        map: &mut MapBuilder,
+        rooms: &[Room],
+    ) {
+        map.set_status("corridors-pass");
+        map.set_notify_every(5);
+
+        // Connect each room to the closest one placed before it - since every
+        // room gets linked to an earlier one, this forms a spanning tree
+        for (idx, room) in rooms.iter().enumerate().skip(1) {
+            let src = room.center();
+
+            let dst = rooms[..idx]
+                .iter()
+                .map(|room| room.center())
+                .min_by_key(|dst| {
+                    let d = *dst - src;
+
+                    d.x.abs() + d.y.abs()
+                })
+                .unwrap();
+
+            let mid = if rng.gen_bool(0.5) {
+                ivec2(dst.x, src.y)
+            } else {
+                ivec2(src.x, dst.y)
+            };
+
+            self.dig(map, src, mid).await;
+            self.dig(map, mid, dst).await;
+        }
+    }
+
+    /// Digs a straight corridor from `src` to `dst`, putting doors wherever it
+    /// crosses a room's wall.
+    async fn dig(&self, map: &mut MapBuilder, src: IVec2, dst: IVec2) {
+        let step = (dst - src).signum();
+        let is_horizontal = step.y == 0;
+        let mut pos = src;
+
+        loop {
+            let tile = map.get(pos);
+
+            if tile.meta[0] == Self::ROOM_WALL {
+                map.set(pos, TileKind::DOOR_OPEN).await;
+            } else if tile.is_void() || tile.is_wall() {
+                map.set(pos, TileKind::FLOOR).await;
+
+                if tile.is_wall() {
+                    map.with(|map| {
+                        map.get_mut(pos).meta[0] = Self::OPENING;
+                    });
+                }
+
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        let is_end =
+                            if is_horizontal { dy == 0 } else { dx == 0 };
+
+                        let wall = if is_end == is_horizontal {
+                            TileKind::WALL_V
+                        } else {
+                            TileKind::WALL_H
+                        };
+
+                        map.set_if_void(pos + ivec2(dx, dy), wall).await;
+                    }
+                }
+            }
+
+            if pos == dst {
+                break;
+            }
+
+            pos += step;
+        }
+    }
+
+    /// Closes some of the doors, putting pressure plates on both of their
+    /// sides.
+    ///
+    /// Each door gets its own channel, so we can close at most 255 of them -
+    /// the rest stays open.
+    async fn doors_pass(&self, rng: &mut impl RngCore, map: &mut MapBuilder) {
+        map.set_status("doors-pass");
+        map.set_notify_every(1);
+
+        let mut doors = Vec::new();
+
+        map.with(|map| {
+            map.for_each(|pos, tile| {
+                if tile.kind == TileKind::DOOR_OPEN {
+                    doors.push(pos);
+                }
+            });
+        });
+
+        let mut channel = 1;
+
+        for door in doors {
+            if !rng.gen_bool(Self::CLOSED_DOOR_PROBABILITY) {
+                continue;
+            }
+
+            let plates = if map.get(door + Dir::N).is_floor()
+                && map.get(door + Dir::S).is_floor()
+            {
+                [door + Dir::N, door + Dir::S]
+            } else {
+                [door + Dir::E, door + Dir::W]
+            };
+
+            if !plates.iter().all(|plate| map.get(*plate).is_floor()) {
+                continue;
+            }
+
+            map.set(door, Tile::door(channel)).await;
+
+            for plate in plates {
+                map.set(plate, Tile::plate(channel)).await;
+            }
+
+            if channel == u8::MAX {
+                break;
+            }
+
+            channel += 1;
+        }
+    }
+
+    fn is_good_enough(&self, map: &Map) -> bool {
+        let mut walkable = AHashSet::new();
+
+        map.for_each(|pos, tile| {
+            if tile.is_floor() || tile.kind == TileKind::DOOR_OPEN {
+                walkable.insert(pos);
+            }
+        });
+
+        // Each door must sit in the middle of a straight wall, leading from
+        // floor to floor - corridors running along rooms' walls or through
+        // their corners produce garbage we don't want
+        let doors_ok = walkable.iter().all(|&pos| {
+            if map.get(pos).kind != TileKind::DOOR_OPEN {
+                return true;
+            }
+
+            let [n, e, s, w] =
+                [Dir::N, Dir::E, Dir::S, Dir::W].map(|dir| map.get(pos + dir));
+
+            (n.is_floor() && s.is_floor() && e.is_wall() && w.is_wall())
+                || (e.is_floor() && w.is_floor() && n.is_wall() && s.is_wall())
+        });
+
+        if !doors_ok {
+            return false;
+        }
+
+        // Similarly, corridors can cross each other, but they can't run along
+        // each other's walls, knocking them down - which we detect by looking
+        // for corridors that have become wider than a single tile
+        let openings_ok = walkable.iter().all(|&pos| {
+            if map.get(pos).meta[0] != Self::OPENING {
+                return true;
+            }
+
+            [ivec2(-1, -1), ivec2(0, -1), ivec2(-1, 0), ivec2(0, 0)]
+                .into_iter()
+                .all(|min| {
+                    [ivec2(0, 0), ivec2(1, 0), ivec2(0, 1), ivec2(1, 1)]
+                        .into_iter()
+                        .any(|off| !walkable.contains(&(pos + min + off)))
+                })
+        });
+
+        if !openings_ok {
+            return false;
+        }
+
+        // All of the floors (and doors) must be reachable from each other
+        let Some(&start) = walkable.iter().next() else {
+            return false;
+        };
+
+        let mut stack = VecDeque::from_iter(iter::once(start));
+
+        walkable.remove(&start);
+
+        while let Some(pos) = stack.pop_front() {
+            for dir in Dir::all() {
+                let pos = pos + dir;
+
+                if walkable.remove(&pos) {
+                    stack.push_back(pos);
+                }
+            }
+        }
+
+        walkable.is_empty()
+    }
+}
+
+impl FromStr for DungeonTheme {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let mut width = None;
+        let mut height = None;
+
+        for entry in spec::entries(spec) {
+            let entry = entry?;
+
+            match entry.key {
+                "width" => {
+                    width = Some(entry.value()?);
+                }
+                "height" => {
+                    height = Some(entry.value()?);
+                }
+                key => {
+                    return Err(anyhow!("unknown key: {key}"));
+                }
+            }
+        }
+
+        let width = width.context("missing key: width")?;
+        let height = height.context("missing key: height")?;
+
+        Ok(Self::new(uvec2(width, height)))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Room {
+    min: IVec2,
+    max: IVec2,
+}
+
+impl Room {
+    fn center(&self) -> IVec2 {
+        (self.min + self.max) / 2
+    }
+
+    fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(self.min).all() && pos.cmple(self.max).all()
+    }
+
+    /// Checks whether rooms would overlap, leaving at least two tiles of
+    /// space between their walls, so that corridors can squeeze in-between.
+    fn collides_with(&self, other: &Room) -> bool {
+        const MARGIN: i32 = 4;
+
+        self.min.x - MARGIN <= other.max.x
+            && other.min.x - MARGIN <= self.max.x
+            && self.min.y - MARGIN <= other.max.y
+            && other.min.y - MARGIN <= self.max.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use kartoffels_utils::Asserter;
+
+    #[test]
+    fn build() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let map = DungeonTheme::from_str("width=64,height=32")
+            .unwrap()
+            .build(&mut rng, MapBuilder::detached())
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        Asserter::new("src/theme/dungeon/tests")
+            .assert("build.txt", map.to_string());
+    }
+}
@@ -0,0 +1,305 @@
+use super::{Map, MapBuilder};
+use crate::{spec, Dir, TileKind};
+use anyhow::{anyhow, Context, Error, Result};
+use glam::{ivec2, uvec2, IVec2, UVec2};
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MazeTheme {
+    size: UVec2,
+
+    #[serde(default, skip_serializing_if = "is_zero")]
+    loops: u8,
+}
+
+impl MazeTheme {
+    /// Smallest maze we can build - a single cell surrounded by walls.
+    pub const MIN_SIZE: UVec2 = uvec2(3, 3);
+
+    pub fn new(size: UVec2) -> Self {
+        Self { size, loops: 0 }
+    }
+
+    /// Sets the probability (in percent) of knocking down each remaining inner
+    /// wall after the maze has been carved - `0` yields an acyclic maze (the
+    /// default), `100` yields a grid of rooms with no walls in-between.
+    ///
+    /// Spec strings provide it as a fraction instead, e.g. `loops=0.1` means
+    /// 10%.
+    pub fn with_loops(mut self, loops: u8) -> Self {
+        self.loops = loops;
+        self
+    }
+
+    pub async fn build(
+        &self,
+        rng: &mut impl RngCore,
+        mut map: MapBuilder,
+    ) -> Result<Map> {
+        Self::validate(self.size, self.loops)?;
+
+        map.begin(self.size);
+
+        // Cells lie on odd coordinates, so for even sizes the last row/column
+        // must stay empty - otherwise the outermost cells wouldn't get walls
+        let area = self.size - (UVec2::ONE - self.size % 2);
+
+        Self::draw_borders(&mut map, area).await;
+
+        Self {
+            size: area,
+            ..*self
+        }
+        .draw(rng, &mut map, ivec2(1, 1))
+        .await;
+
+        Ok(map.commit())
+    }
+
+    async fn draw_borders(map: &mut MapBuilder, area: UVec2) {
+        let area = area.as_ivec2();
+
+        map.line(ivec2(0, 0), ivec2(area.x - 1, 0), TileKind::WALL_H)
+            .await;
+
+        map.line(ivec2(0, 1), ivec2(0, area.y - 2), TileKind::WALL_V)
+            .await;
+
+        map.line(
+            ivec2(0, area.y - 1),
+            ivec2(area.x - 1, area.y - 1),
+            TileKind::WALL_H,
+        )
+        .await;
+
+        map.line(
+            ivec2(area.x - 1, 1),
+            ivec2(area.x - 1, area.y - 2),
+            TileKind::WALL_V,
+        )
+        .await;
+    }
+
+    /// Draws maze within `(0,0)..self.size`, starting from `head`, using the
+    /// recursive backtracking algorithm, a'la
+    /// https://weblog.jamisbuck.org/2010/12/27/maze-generation-recursive-backtracking
+    ///
+    /// Assumes the map has been already started with `map.begin()`.
+    pub async fn draw(
+        &self,
+        rng: &mut impl RngCore,
+        map: &mut MapBuilder,
+        head: IVec2,
+    ) {
+        const NOT_VISITED: u8 = 0;
+        const VISITED: u8 = 1;
+
+        let mut frontier = Vec::new();
+
+        for dir in Dir::shuffled(rng) {
+            frontier.push((head, dir));
+        }
+
+        map.with(|map| {
+            map.get_mut(head).meta[0] = VISITED;
+        });
+
+        while !frontier.is_empty() {
+            let idx = rng.gen_range(0..frontier.len());
+            let (src, dir) = frontier.swap_remove(idx);
+            let mid = src + dir;
+            let dst = mid + dir;
+
+            if map.get(src).is_void() {
+                map.with(|map| {
+                    map.get_mut(src).kind = TileKind::FLOOR;
+                });
+
+                map.set_if_void(src - ivec2(1, 0), TileKind::WALL_V).await;
+                map.set_if_void(src + ivec2(1, 0), TileKind::WALL_V).await;
+                map.set_if_void(src - ivec2(0, 1), TileKind::WALL_H).await;
+                map.set_if_void(src + ivec2(0, 1), TileKind::WALL_H).await;
+            }
+
+            if self.contains(dst) && map.get(dst).meta[0] == NOT_VISITED {
+                map.with(|map| {
+                    map.get_mut(dst).meta[0] = VISITED;
+                });
+
+                Self::carve(map, mid, dir).await;
+
+                for dir in Dir::shuffled(rng) {
+                    frontier.push((dst, dir));
+                }
+            }
+        }
+
+        map.with(|map| {
+            map.for_each_mut(|_, tile| {
+                tile.meta[0] = 0;
+            });
+        });
+
+        if self.loops > 0 {
+            self.draw_loops(rng, map, head).await;
+        }
+    }
+
+    async fn draw_loops(
+        &self,
+        rng: &mut impl RngCore,
+        map: &mut MapBuilder,
+        head: IVec2,
+    ) {
+        for y in 0..self.size.y as i32 {
+            for x in 0..self.size.x as i32 {
+                let src = ivec2(x, y);
+
+                if (src - head) % 2 != IVec2::ZERO || !map.get(src).is_floor() {
+                    continue;
+                }
+
+                for dir in [Dir::E, Dir::S] {
+                    let mid = src + dir;
+                    let dst = mid + dir;
+
+                    if self.contains(dst)
+                        && map.get(dst).is_floor()
+                        && map.get(mid).is_wall()
+                        && rng.gen_ratio(self.loops as u32, 100)
+                    {
+                        Self::carve(map, mid, dir).await;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn carve(map: &mut MapBuilder, mid: IVec2, dir: Dir) {
+        map.set(mid, TileKind::FLOOR).await;
+
+        match dir {
+            Dir::N | Dir::S => {
+                map.set(mid - ivec2(1, 0), TileKind::WALL_V).await;
+                map.set(mid + ivec2(1, 0), TileKind::WALL_V).await;
+            }
+
+            Dir::E | Dir::W => {
+                map.set(mid - ivec2(0, 1), TileKind::WALL_H).await;
+                map.set(mid + ivec2(0, 1), TileKind::WALL_H).await;
+            }
+        }
+    }
+
+    fn validate(size: UVec2, loops: u8) -> Result<()> {
+        if size.cmplt(Self::MIN_SIZE).any() {
+            return Err(anyhow!(
+                "maze must be at least {}x{}",
+                Self::MIN_SIZE.x,
+                Self::MIN_SIZE.y,
+            ));
+        }
+
+        if loops > 100 {
+            return Err(anyhow!("`loops` must be within 0..=100"));
+        }
+
+        Ok(())
+    }
+
+    fn contains(&self, pos: IVec2) -> bool {
+        pos.x >= 0
+            && pos.y >= 0
+            && pos.x < self.size.x as i32
+            && pos.y < self.size.y as i32
+    }
+}
+
+impl FromStr for MazeTheme {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let mut width = None;
+        let mut height = None;
+        let mut loops = None;
+
+        for entry in spec::entries(spec) {
+            let entry = entry?;
+
+            match entry.key {
+                "width" => {
+                    width = Some(entry.value()?);
+                }
+                "height" => {
+                    height = Some(entry.value()?);
+                }
+                "loops" => {
+                    let density: f32 = entry.value()?;
+
+                    if !(0.0..=1.0).contains(&density) {
+                        return Err(anyhow!(
+                            "`loops` must be within 0.0..=1.0"
+                        ));
+                    }
+
+                    loops = Some((density * 100.0).round() as u8);
+                }
+                key => {
+                    return Err(anyhow!("unknown key: {key}"));
+                }
+            }
+        }
+
+        let size = uvec2(
+            width.context("missing key: width")?,
+            height.context("missing key: height")?,
+        );
+        let loops = loops.unwrap_or(0);
+
+        Self::validate(size, loops)?;
+
+        Ok(Self::new(size).with_loops(loops))
+    }
+}
+
+fn is_zero(val: &u8) -> bool {
+    *val == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use kartoffels_utils::Asserter;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use test_case::test_case;
+
+    #[test_case("acyclic", "width=40,height=20")]
+    #[test_case("cyclic", "width=41,height=21,loops=0.1")]
+    fn build(case: &str, spec: &str) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let map = MazeTheme::from_str(spec)
+            .unwrap()
+            .build(&mut rng, MapBuilder::detached())
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        Asserter::new("src/theme/maze/tests")
+            .assert(format!("build-{case}.txt"), map.to_string());
+    }
+
+    #[test]
+    fn from_str_err() {
+        assert!(MazeTheme::from_str("width=40").is_err());
+        assert!(MazeTheme::from_str("width=0,height=20").is_err());
+        assert!(MazeTheme::from_str("width=40,height=2").is_err());
+        assert!(MazeTheme::from_str("width=40,height=20,loops=10").is_err());
+        assert!(MazeTheme::from_str("width=40,height=20,loops=1.1").is_err());
+        assert!(MazeTheme::from_str("width=40,height=20,loops=-0.1").is_err());
+    }
+}
@@ -4,6 +4,7 @@ mod world_id;
 mod world_name;
 mod world_path;
 mod world_rng;
+mod world_threads;
 
 pub use self::dir::*;
 pub use self::ticks::*;
@@ -11,3 +12,4 @@ pub use self::world_id::*;
 pub use self::world_name::*;
 pub use self::world_path::*;
 pub use self::world_rng::*;
+pub use self::world_threads::*;
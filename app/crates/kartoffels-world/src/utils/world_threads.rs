@@ -0,0 +1,32 @@
+use bevy_ecs::system::Resource;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::LazyLock;
+
+/// Threads used to run bots' CPUs.
+///
+/// The pool itself is shared by all worlds and lives as long as the process
+/// does, so ticking a large world doesn't have to spawn any threads - each
+/// world just decides how many of those threads it's allowed to occupy.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct WorldThreads(usize);
+
+impl WorldThreads {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self(limit.unwrap_or_else(|| POOL.current_num_threads()).max(1))
+    }
+
+    pub fn get(&self) -> usize {
+        self.0
+    }
+
+    pub fn pool(&self) -> &'static ThreadPool {
+        &POOL
+    }
+}
+
+static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
+    ThreadPoolBuilder::new()
+        .thread_name(|idx| format!("kartoffels-cpu-{idx}"))
+        .build()
+        .expect("couldn't create thread pool")
+});
@@ -1,12 +1,14 @@
-use glam::{ivec2, uvec2};
+use glam::{ivec2, uvec2, IVec2};
 use indoc::indoc;
 use kartoffels_prefabs::{DUMMY, ROBERTO};
-use kartoffels_utils::{Asserter, ErrorExt};
+use kartoffels_utils::{Asserter, ErrorExt, Id};
 use kartoffels_world::prelude::*;
 use std::future::Future;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use tempfile::NamedTempFile;
+use tokio::time;
 
 #[tokio::test]
 async fn smoke() {
@@ -70,6 +72,41 @@ async fn pause_and_resume() {
     assert_ne!(snap3.bots, snap4.bots);
 }
 
+#[tokio::test]
+async fn determinism() {
+    // Enough bots for the world to tick them in parallel
+    async fn run(threads: Option<usize>) -> Arc<Snapshot> {
+        let world = kartoffels_world::create(Config {
+            policy: Policy {
+                max_alive_bots: 192,
+                ..config().policy
+            },
+            theme: Some(Theme::Arena(ArenaTheme::new(32))),
+            threads,
+            ..config()
+        });
+
+        for _ in 0..192 {
+            world
+                .create_bot(CreateBotRequest::new(ROBERTO))
+                .await
+                .unwrap();
+        }
+
+        world.tick(10_000).await.unwrap();
+        world.snapshot().await
+    }
+
+    let snap1 = run(Some(1)).await;
+    let snap2 = run(Some(4)).await;
+    let snap3 = run(Some(4)).await;
+
+    assert_eq!(snap1.map, snap2.map);
+    assert_eq!(snap1.bots, snap2.bots);
+    assert_eq!(snap2.map, snap3.map);
+    assert_eq!(snap2.bots, snap3.bots);
+}
+
 #[tokio::test]
 async fn kill_bot() {
     let world = kartoffels_world::create(config());
@@ -178,6 +215,100 @@ async fn set_map() {
     assert_eq!(uvec2(22, 11), world.snapshot().await.tiles.size());
 }
 
+#[tokio::test]
+async fn regenerate_map() {
+    let world = kartoffels_world::create(config());
+
+    let bot = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(12, 12)).instant())
+        .await
+        .unwrap();
+
+    let gem = world
+        .create_object(Object::new(ObjectKind::GEM), ivec2(10, 10))
+        .await
+        .unwrap();
+
+    world.regenerate_map().await.unwrap();
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert_eq!(uvec2(25, 25), snap.map.size());
+    assert!(snap.bots.alive.has(bot));
+
+    // Objects lying on the old map get carried over to the new one
+    let gems: Vec<_> = snap
+        .objects
+        .iter()
+        .filter(|obj| obj.pos.is_some())
+        .map(|obj| obj.id)
+        .collect();
+
+    assert_eq!(vec![gem], gems);
+
+    // ---
+
+    let world = kartoffels_world::create(Config {
+        theme: None,
+        ..config()
+    });
+
+    assert_eq!(
+        "world doesn't have a theme",
+        world.regenerate_map().await.unwrap_err().to_string()
+    );
+}
+
+#[tokio::test]
+async fn clear_bots() {
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            auto_respawn: false,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    let roberto = world
+        .create_bot(
+            CreateBotRequest::new(ROBERTO)
+                .at(ivec2(11, 12))
+                .facing(Dir::E)
+                .instant(),
+        )
+        .await
+        .unwrap();
+
+    let dummy = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(12, 12)).instant())
+        .await
+        .unwrap();
+
+    world.tick(1).await.unwrap();
+
+    while world.snapshot().await.bots.alive.has(dummy) {
+        world.tick(10_000).await.unwrap();
+    }
+
+    let standings = world.clear_bots().await.unwrap();
+
+    assert_eq!(
+        vec![(roberto, 1, 1), (dummy, 0, 1)],
+        standings
+            .iter()
+            .map(|bot| (bot.id, bot.score, bot.lives))
+            .collect::<Vec<_>>(),
+    );
+
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert!(snap.bots.is_empty());
+    assert!(snap.lives.get(roberto).is_none());
+}
+
 #[tokio::test]
 async fn set_spawn() {
     let world = kartoffels_world::create(config());
@@ -234,6 +365,529 @@ async fn set_spawn() {
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn spawn_zones() {
+    async fn positions(world: &Handle) -> Vec<IVec2> {
+        world.tick(1).await.unwrap();
+
+        world
+            .snapshot()
+            .await
+            .bots
+            .alive
+            .iter_sorted_by_birth()
+            .map(|bot| bot.pos)
+            .collect()
+    }
+
+    // Round-robin: bots get born in consecutive zones, until all are taken
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            spawn: SpawnPolicy {
+                zones: vec![
+                    SpawnZone::rect("a", ivec2(10, 12), ivec2(10, 12)),
+                    SpawnZone::rect("b", ivec2(14, 12), ivec2(14, 12)),
+                ],
+                strategy: SpawnStrategy::RoundRobin,
+            },
+            ..config().policy
+        },
+        ..config()
+    });
+
+    for _ in 0..2 {
+        world
+            .create_bot(CreateBotRequest::new(DUMMY).instant())
+            .await
+            .unwrap();
+    }
+
+    let err = world
+        .create_bot(CreateBotRequest::new(DUMMY).instant())
+        .await
+        .unwrap_err();
+
+    assert_eq!("couldn't determine spawn point", err.to_string());
+    assert_eq!(vec![ivec2(10, 12), ivec2(14, 12)], positions(&world).await);
+
+    // Farthest: bot gets born as far as possible from the other bots
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            spawn: SpawnPolicy {
+                zones: vec![SpawnZone::rect("a", ivec2(5, 12), ivec2(19, 12))],
+                strategy: SpawnStrategy::Farthest,
+            },
+            ..config().policy
+        },
+        ..config()
+    });
+
+    world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(5, 12)).instant())
+        .await
+        .unwrap();
+
+    world
+        .create_bot(CreateBotRequest::new(DUMMY).instant())
+        .await
+        .unwrap();
+
+    world
+        .create_bot(CreateBotRequest::new(DUMMY).instant())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![ivec2(5, 12), ivec2(19, 12), ivec2(12, 12)],
+        positions(&world).await
+    );
+
+    // Per-team: bot gets born in its team's zone, unless the zone is full
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            spawn: SpawnPolicy {
+                zones: vec![
+                    SpawnZone::rect("a", ivec2(10, 12), ivec2(10, 12))
+                        .with_team(Team::new(0)),
+                    SpawnZone::rect("b", ivec2(14, 12), ivec2(14, 12))
+                        .with_team(Team::new(1)),
+                    SpawnZone::rect("c", ivec2(12, 12), ivec2(12, 12)),
+                ],
+                strategy: SpawnStrategy::PerTeam,
+            },
+            teams: 2,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    for team in [1, 0, 1] {
+        world
+            .create_bot(
+                CreateBotRequest::new(DUMMY).team(Team::new(team)).instant(),
+            )
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        vec![ivec2(14, 12), ivec2(10, 12), ivec2(12, 12)],
+        positions(&world).await
+    );
+}
+
+#[tokio::test]
+async fn custom_theme() {
+    let theme = CustomTheme::new(indoc! {"
+        -------
+        |A.*.B|
+        |.|.|.|
+        |..!..|
+        -------
+    "})
+    .unwrap();
+
+    let world = kartoffels_world::create(Config {
+        theme: Some(Theme::Custom(theme)),
+        ..config()
+    });
+
+    // First two bots get born at the anchors, third one doesn't get born since
+    // all spawn points are taken
+    for _ in 0..3 {
+        world
+            .create_bot(CreateBotRequest::new(DUMMY))
+            .await
+            .unwrap();
+    }
+
+    // ---
+
+    let snapshot = world.snapshots().next().await.unwrap();
+
+    let mut actual: Vec<_> = snapshot
+        .bots
+        .alive
+        .iter_sorted_by_birth()
+        .map(|bot| bot.pos)
+        .collect();
+
+    actual.sort_by_key(|pos| pos.x);
+
+    assert_eq!(vec![ivec2(1, 1), ivec2(5, 1)], actual);
+
+    let actual: Vec<_> = snapshot
+        .objects
+        .iter()
+        .map(|obj| (obj.obj.kind, obj.pos))
+        .collect();
+
+    assert_eq!(2, actual.len());
+    assert!(actual.contains(&(ObjectKind::GEM, Some(ivec2(3, 1)))));
+    assert!(actual.contains(&(ObjectKind::MINE, Some(ivec2(3, 3)))));
+}
+
+#[tokio::test]
+async fn teams() {
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            auto_respawn: false,
+            teams: 2,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    let create = |req: CreateBotRequest| {
+        let world = &world;
+
+        async move {
+            world
+                .create_bot(req.at(ivec2(12, 12)).facing(Dir::E).instant())
+                .await
+                .unwrap()
+        }
+    };
+
+    let roberto = world
+        .create_bot(
+            CreateBotRequest::new(ROBERTO)
+                .at(ivec2(11, 12))
+                .facing(Dir::E)
+                .instant(),
+        )
+        .await
+        .unwrap();
+
+    // Killing a teammate doesn't give any points
+    let friend = create(CreateBotRequest::new(DUMMY).team(Team::new(0))).await;
+
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert_eq!(Some(Team::new(0)), snap.bots.team_of(roberto));
+    assert_eq!(Some(Team::new(0)), snap.bots.team_of(friend));
+
+    while world.snapshot().await.bots.alive.has(friend) {
+        world.tick(10_000).await.unwrap();
+    }
+
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert_eq!(0, snap.bots.alive.get(roberto).unwrap().score);
+
+    // Killing a foe does - and since team #0 is now the bigger one, the foe
+    // lands in team #1
+    let foe = create(CreateBotRequest::new(DUMMY)).await;
+
+    world.tick(1).await.unwrap();
+
+    assert_eq!(Some(Team::new(1)), world.snapshot().await.bots.team_of(foe));
+
+    while world.snapshot().await.bots.alive.has(foe) {
+        world.tick(10_000).await.unwrap();
+    }
+
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert_eq!(1, snap.bots.alive.get(roberto).unwrap().score);
+
+    // Lives remember which team the bot was in
+    assert_eq!(
+        Some(Team::new(1)),
+        snap.lives.iter(foe).next().unwrap().team
+    );
+
+    // Teams must exist
+    let err = world
+        .create_bot(CreateBotRequest::new(DUMMY).team(Team::new(2)))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert_eq!("team 2 doesn't exist, world has 2 teams", err);
+}
+
+#[tokio::test]
+async fn king_of_the_hill() {
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            auto_respawn: false,
+            mode: GameMode::KingOfTheHill {
+                hill: GameZone::new(ivec2(10, 12), ivec2(12, 12)),
+            },
+            teams: 2,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    let create = |pos: IVec2, team: u8| {
+        let world = &world;
+
+        async move {
+            world
+                .create_bot(
+                    CreateBotRequest::new(DUMMY)
+                        .at(pos)
+                        .team(Team::new(team))
+                        .instant(),
+                )
+                .await
+                .unwrap()
+        }
+    };
+
+    let scores = |bots: [BotId; 2]| {
+        let world = &world;
+
+        async move {
+            let snap = world.snapshot().await;
+
+            bots.map(|id| snap.bots.alive.get(id).unwrap().score)
+        }
+    };
+
+    // Uncontested hill gives a point per second
+    let king = create(ivec2(11, 12), 0).await;
+    let rival = create(ivec2(5, 5), 1).await;
+
+    world.tick(64_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!([1, 0], scores([king, rival]).await);
+
+    // Contested hill doesn't give anything
+    world.kill_bot(rival, "moved").await.unwrap();
+
+    let rival = create(ivec2(12, 12), 1).await;
+
+    world.tick(64_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!([1, 0], scores([king, rival]).await);
+
+    // ... unless it's contested by a teammate
+    world.kill_bot(rival, "moved").await.unwrap();
+
+    let friend = create(ivec2(12, 12), 0).await;
+
+    world.tick(64_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!([2, 1], scores([king, friend]).await);
+}
+
+#[tokio::test]
+async fn hazards() {
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            auto_respawn: false,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    world.tick(1).await.unwrap();
+
+    let mut map = Map::new(uvec2(7, 5));
+
+    map.fill(TileKind::FLOOR);
+    world.set_map(map.clone()).await.unwrap();
+
+    let burned = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(1, 1)).instant())
+        .await
+        .unwrap();
+
+    let pushed = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(3, 1)).instant())
+        .await
+        .unwrap();
+
+    // Conveyors push bots just like motors do, so mines should go off
+    let mined = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(3, 3)).instant())
+        .await
+        .unwrap();
+
+    world
+        .create_object(Object::new(ObjectKind::MINE), ivec2(5, 3))
+        .await
+        .unwrap();
+
+    map.set(ivec2(1, 1), TileKind::LAVA);
+    map.set(ivec2(3, 1), Tile::conveyor(Dir::E));
+    map.set(ivec2(3, 3), Tile::conveyor(Dir::E));
+    world.set_map(map).await.unwrap();
+
+    // ---
+
+    world.tick(20_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert!(snap.bots.alive.has(burned));
+    assert_eq!(ivec2(4, 1), snap.bots.alive.get(pushed).unwrap().pos);
+    assert!(snap.bots.dead.has(mined));
+    assert!(snap.objects.is_empty());
+
+    // ---
+
+    world.tick(20_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert!(snap.bots.dead.has(burned));
+    assert_eq!(ivec2(4, 1), snap.bots.alive.get(pushed).unwrap().pos);
+}
+
+#[tokio::test]
+async fn doors() {
+    let world = kartoffels_world::create(config());
+
+    world.tick(1).await.unwrap();
+
+    let mut map = Map::new(uvec2(7, 3));
+
+    map.fill(TileKind::FLOOR);
+    map.set(ivec2(5, 1), Tile::door(1));
+    world.set_map(map).await.unwrap();
+
+    let door = |snap: &Snapshot| snap.map.get(ivec2(5, 1)).kind;
+
+    let bot = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(1, 1)).instant())
+        .await
+        .unwrap();
+
+    world.tick(1).await.unwrap();
+
+    assert_eq!(TileKind::DOOR, door(&*world.snapshot().await));
+
+    // Plate on another channel does nothing
+    world.set_tile(ivec2(1, 1), Tile::plate(2)).await.unwrap();
+    world.tick(1).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!(TileKind::DOOR, door(&*world.snapshot().await));
+
+    // Plate on the door's channel opens it...
+    world.set_tile(ivec2(1, 1), Tile::plate(1)).await.unwrap();
+    world.tick(1).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!(TileKind::DOOR_OPEN, door(&*world.snapshot().await));
+
+    // ... for as long as there's someone standing on it
+    world.delete_bot(bot).await.unwrap();
+    world.tick(1).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!(TileKind::DOOR, door(&*world.snapshot().await));
+
+    // Channel zero is not wired to anything
+    world.set_tile(ivec2(1, 1), TileKind::FLOOR).await.unwrap();
+
+    let bot = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(1, 1)).instant())
+        .await
+        .unwrap();
+
+    world.set_tile(ivec2(5, 1), Tile::door(0)).await.unwrap();
+    world.set_tile(ivec2(1, 1), Tile::plate(0)).await.unwrap();
+    world.tick(1).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert_eq!(TileKind::DOOR, door(&*world.snapshot().await));
+
+    world.delete_bot(bot).await.unwrap();
+
+    // ---
+
+    assert_eq!(
+        "7,3 is outside of the map",
+        world
+            .set_tile(ivec2(7, 3), TileKind::FLOOR)
+            .await
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[tokio::test]
+async fn fog_of_war() {
+    let world = kartoffels_world::create(Config {
+        policy: Policy {
+            fog: true,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    let alice = Owner::new(Id::new(1));
+    let bob = Owner::new(Id::new(2));
+
+    let bot = world
+        .create_bot(
+            CreateBotRequest::new(ROBERTO)
+                .at(ivec2(12, 12))
+                .owner(alice)
+                .instant(),
+        )
+        .await
+        .unwrap();
+
+    world
+        .create_object(Object::new(ObjectKind::GEM), ivec2(12, 1))
+        .await
+        .unwrap();
+
+    world.tick(64_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+    let alice_snap = snap.fogged(alice);
+    let bob_snap = snap.fogged(bob);
+
+    assert!(!snap.map.get(ivec2(12, 12)).is_void());
+    assert!(!snap.map.get(ivec2(12, 1)).is_void());
+
+    assert!(!alice_snap.map.get(ivec2(12, 12)).is_void());
+    assert!(alice_snap.map.get(ivec2(12, 1)).is_void());
+
+    bob_snap.map.for_each(|pos, tile| {
+        assert!(tile.is_void(), "{pos}");
+    });
+
+    assert!(snap.bots.alive.has(bot));
+    assert!(alice_snap.bots.alive.has(bot));
+    assert!(!bob_snap.bots.alive.has(bot));
+
+    assert!(!snap.objects.is_empty());
+    assert!(alice_snap.objects.is_empty());
+    assert!(bob_snap.objects.is_empty());
+
+    assert!(Arc::ptr_eq(&alice_snap, &snap.fogged(alice)));
+
+    // ---
+
+    let world = kartoffels_world::create(config());
+
+    world.tick(1).await.unwrap();
+
+    let snap = world.snapshot().await;
+
+    assert!(Arc::ptr_eq(&snap, &snap.fogged(alice)));
+}
+
 #[tokio::test]
 async fn with_auto_respawn() {
     let world = kartoffels_world::create(Config {
@@ -332,6 +986,11 @@ async fn resume() {
         .await
         .unwrap();
 
+    let obj = world
+        .create_object(Object::new(ObjectKind::FLAG), ivec2(1, 2))
+        .await
+        .unwrap();
+
     // ---
 
     world.shutdown().await.unwrap();
@@ -347,27 +1006,88 @@ async fn resume() {
 
     world.tick(1).await.unwrap();
 
-    let actual: Vec<_> = world
-        .snapshot()
-        .await
-        .bots
-        .alive
+    let snapshot = world.snapshot().await;
+
+    let actual: Vec<_> = snapshot.bots.alive.iter().map(|bot| bot.id).collect();
+
+    assert_eq!(vec![bot], actual);
+
+    let actual: Vec<_> = snapshot
+        .objects
         .iter()
-        .map(|bot| bot.id)
+        .map(|obj| (obj.id, obj.obj, obj.pos))
         .collect();
 
-    let expected = vec![bot];
+    let expected =
+        vec![(obj, Object::new(ObjectKind::FLAG), Some(ivec2(1, 2)))];
 
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn resume_terrain() {
+    let file = NamedTempFile::new().unwrap();
+
+    let world = kartoffels_world::create(Config {
+        path: Some(file.path().to_owned()),
+        policy: Policy {
+            auto_respawn: false,
+            ..config().policy
+        },
+        ..config()
+    });
+
+    world.tick(1).await.unwrap();
+
+    let mut map = Map::new(uvec2(3, 3));
+
+    map.fill(TileKind::FLOOR);
+    world.set_map(map.clone()).await.unwrap();
+
+    let bot = world
+        .create_bot(CreateBotRequest::new(DUMMY).at(ivec2(1, 1)).instant())
+        .await
+        .unwrap();
+
+    map.set(ivec2(1, 1), TileKind::LAVA);
+    world.set_map(map).await.unwrap();
+
+    world.tick(30_000).await.unwrap();
+    world.tick(1).await.unwrap();
+
+    assert!(world.snapshot().await.bots.alive.has(bot));
+
+    world.shutdown().await.unwrap();
+
+    // ---
+
+    // Time spent on lava before the restart must be taken into account
+    let world = kartoffels_world::resume(world.id(), file.path()).unwrap();
+
+    world.overclock(Clock::manual()).await.unwrap();
+    world.tick(5_000).await.unwrap();
+
+    // Resumed world starts with the normal clock, which throttles snapshots -
+    // give it a moment, so that the next tick refreshes the snapshot
+    time::sleep(Duration::from_millis(50)).await;
+    world.tick(1).await.unwrap();
+
+    assert!(world.snapshot().await.bots.dead.has(bot));
+}
+
 #[tokio::test]
 async fn err_too_many_bots_queued() {
     let world = kartoffels_world::create(Config {
         policy: Policy {
             auto_respawn: true,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: 10,
             max_queued_bots: 20,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
         },
         ..config()
     });
@@ -388,6 +1108,19 @@ async fn err_too_many_bots_queued() {
     assert_eq!("too many bots queued, try again in a moment", err);
 }
 
+#[tokio::test]
+async fn err_world_doesnt_have_teams() {
+    let world = kartoffels_world::create(config());
+
+    let err = world
+        .create_bot(CreateBotRequest::new(DUMMY).team(Team::new(0)))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert_eq!("world doesn't have teams", err);
+}
+
 #[tokio::test]
 async fn err_couldnt_parse_firmware_1() {
     let actual = kartoffels_world::create(config())
@@ -442,11 +1175,18 @@ fn config() -> Config {
         path: None,
         policy: Policy {
             auto_respawn: true,
+            bot_hp: 1,
+            fog: false,
             max_alive_bots: 16,
             max_queued_bots: 16,
+            mode: Default::default(),
+            spawn: Default::default(),
+            teams: 0,
+            terraforming: false,
         },
         seed: Some(Default::default()),
         theme: Some(Theme::Arena(ArenaTheme::new(12))),
+        threads: None,
     }
 }
 